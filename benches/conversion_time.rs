@@ -0,0 +1,38 @@
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use std::hint::black_box;
+use std::time::Duration;
+use tzt::ConversionTime;
+
+const PARSES: u64 = 1_000_000;
+
+/// Parses a million inputs, one per accepted format in turn; criterion
+/// reports the throughput in parses per second.
+fn parse_a_million_inputs(c: &mut Criterion) {
+    let inputs = [
+        "2024-06-27 12:34:56",
+        "2024-06-27T12:34:56.789",
+        "2024-06-27",
+        "2024-06-27 12:34:56+09:00",
+        "2024-06-27T12:34:56Z",
+    ];
+    assert!(inputs
+        .iter()
+        .all(|input| input.parse::<ConversionTime>().is_ok()));
+
+    let mut group = c.benchmark_group("conversion_time");
+    group.throughput(Throughput::Elements(PARSES));
+    // one iteration takes most of a second
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+    group.bench_function("parse_a_million_inputs", |b| {
+        b.iter(|| {
+            for i in 0..PARSES as usize {
+                let _ = black_box(black_box(&inputs[i % inputs.len()]).parse::<ConversionTime>());
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parse_a_million_inputs);
+criterion_main!(benches);
@@ -1,13 +1,50 @@
 pub mod ambiguous_time_strategy;
+pub mod clock;
 pub mod conversion_time;
+pub mod dst_gap;
+pub mod epoch_time;
+pub mod epoch_unit;
+pub mod gap_time_strategy;
+pub mod input_format;
+pub mod list_order;
+pub mod list_request;
+pub mod output_format;
+pub mod output_precision;
+pub mod place_lookup;
+pub mod preferred_region;
+pub mod relative_time;
+pub mod requested_time;
 pub mod source_timezone;
 pub mod target_timezone;
+pub mod timezone_abbreviation;
+pub mod timezone_name_match;
 pub mod timezone_parse_error;
 pub mod translation_request;
+pub mod utc_offset;
+pub mod zone;
+pub mod zone_parse_options;
+pub mod zone_transition;
 
 pub use self::ambiguous_time_strategy::{AmbiguousTimeStrategy, AmbiguousTimeStrategyParseError};
+pub use self::clock::{Clock, FixedClock};
 pub use self::conversion_time::{ConversionTime, ConversionTimeParseError};
+pub use self::dst_gap::DstGap;
+pub use self::epoch_time::{EpochTime, EpochTimeParseError};
+pub use self::epoch_unit::{EpochUnit, EpochUnitParseError};
+pub use self::gap_time_strategy::{GapTimeStrategy, GapTimeStrategyParseError};
+pub use self::input_format::{InputFormat, InputFormatParseError};
+pub use self::list_order::{ListOrder, ListOrderParseError};
+pub use self::list_request::ListRequest;
+pub use self::output_format::{OutputFormat, OutputFormatError, OutputFormatParseError};
+pub use self::output_precision::{OutputPrecision, OutputPrecisionParseError};
+pub use self::preferred_region::{PreferredRegion, PreferredRegionParseError};
+pub use self::relative_time::{RelativeTime, RelativeTimeParseError};
+pub use self::requested_time::RequestedTime;
 pub use self::source_timezone::SourceTimezone;
 pub use self::target_timezone::TargetTimezone;
 pub use self::timezone_parse_error::TimezoneParseError;
-pub use self::translation_request::TranslationRequest;
+pub use self::translation_request::{TranslationRequest, TranslationRequestBuilder};
+pub use self::utc_offset::{UtcOffset, UtcOffsetParseError};
+pub use self::zone::Zone;
+pub use self::zone_parse_options::ZoneParseOptions;
+pub use self::zone_transition::ZoneTransition;
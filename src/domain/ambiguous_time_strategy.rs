@@ -3,16 +3,22 @@ use std::str::FromStr;
 /// Strategy to resolve an ambiguous local time.
 ///
 /// When DST ends, clocks are set back and the same local time
-/// occurs twice. This strategy decides which occurrence to use.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// occurs twice. This strategy decides which occurrence to use,
+/// whether to use both, or whether that is an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum AmbiguousTimeStrategy {
+    #[default]
     Earliest,
     Latest,
+    /// Fail with `TranslationError::AmbiguousTime`.
+    Error,
+    /// Translate both occurrences, earliest first.
+    Both,
 }
 
 #[derive(thiserror::Error, Debug, PartialEq, Eq)]
 #[error(
-    "Validation Error: Invalid ambiguous time strategy found. {0} (expected: earliest, latest)"
+    "Validation Error: Invalid ambiguous time strategy found. {0} (expected: earliest, latest, error, both)"
 )]
 pub struct AmbiguousTimeStrategyParseError(pub String);
 
@@ -23,11 +29,25 @@ impl FromStr for AmbiguousTimeStrategy {
         match text {
             "earliest" => Ok(AmbiguousTimeStrategy::Earliest),
             "latest" => Ok(AmbiguousTimeStrategy::Latest),
+            "error" => Ok(AmbiguousTimeStrategy::Error),
+            "both" => Ok(AmbiguousTimeStrategy::Both),
             _ => Err(AmbiguousTimeStrategyParseError(text.to_string())),
         }
     }
 }
 
+impl AmbiguousTimeStrategy {
+    /// The name `FromStr` accepts for this strategy.
+    pub fn name(&self) -> &'static str {
+        match self {
+            AmbiguousTimeStrategy::Earliest => "earliest",
+            AmbiguousTimeStrategy::Latest => "latest",
+            AmbiguousTimeStrategy::Error => "error",
+            AmbiguousTimeStrategy::Both => "both",
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -56,6 +76,22 @@ mod tests {
         assert_eq!(strategy, AmbiguousTimeStrategy::Latest);
     }
 
+    #[test]
+    fn parses_error_and_both() {
+        // Arrange
+        let inputs = ["error", "both"];
+
+        // Act
+        let strategies: Vec<AmbiguousTimeStrategy> =
+            inputs.iter().map(|name| name.parse().unwrap()).collect();
+
+        // Assert
+        assert_eq!(
+            strategies,
+            vec![AmbiguousTimeStrategy::Error, AmbiguousTimeStrategy::Both]
+        );
+    }
+
     #[test]
     fn rejects_unknown_strategy() {
         // Arrange
@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+
+/// Source of the current instant.
+///
+/// Relative time expressions (`now`, `tomorrow 09:00`) depend on when
+/// they are evaluated. Taking the clock as a parameter keeps that
+/// dependency visible and lets tests pin "now" to a fixed instant.
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// A clock that always answers the same instant, for tests and
+/// reproducible runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+#[cfg(test)]
+impl FixedClock {
+    /// Fixture: "now" is Thursday 2024-06-27 12:00:00 UTC, in northern
+    /// summer.
+    pub(crate) fn fixture() -> Self {
+        use chrono::TimeZone;
+        FixedClock(Utc.with_ymd_and_hms(2024, 6, 27, 12, 0, 0).unwrap())
+    }
+}
@@ -1,85 +1,277 @@
-use chrono::NaiveDateTime;
+use crate::domain::input_format::InputFormat;
+use chrono::{DateTime, FixedOffset, NaiveDateTime};
+use regex::Regex;
+use std::ops::Range;
 use std::str::FromStr;
+use std::sync::OnceLock;
 
 /// The wall-clock time to convert, before a timezone is attached.
 ///
-/// Construction is only possible through `FromStr`, so holding a
-/// `ConversionTime` proves the input matched one of the accepted formats.
+/// Construction is only possible through parsing, so holding a
+/// `ConversionTime` proves the input matched one of the accepted formats
+/// (or one of the user's `InputFormat`s).
+///
+/// When the input carried an explicit UTC offset (`+09:00`, `Z`), the
+/// offset is kept: the time then names a single instant on its own.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct ConversionTime(NaiveDateTime);
+pub struct ConversionTime {
+    datetime: NaiveDateTime,
+    offset: Option<FixedOffset>,
+}
 
 /// Accepted input formats.
 ///
 /// Each entry pairs a strict regex (rejects unpadded digits, which
-/// chrono alone would accept) with the chrono format used for parsing.
+/// chrono alone would accept) with the chrono format used for parsing,
+/// plus a human-readable description for error messages.
 /// A date without a time is completed with midnight before parsing.
-const ACCEPTED_FORMATS: [AcceptedFormat; 3] = [
+/// Seconds may carry a fraction of 1 to 9 digits (down to nanoseconds).
+/// Formats with an offset accept `Z` as a synonym for `+00:00` (RFC 3339).
+///
+/// Each regex is compiled on first use and kept for the life of the
+/// process, so bulk parsing (`--stdin`) pays for it once.
+static ACCEPTED_FORMATS: [AcceptedFormat; 5] = [
     AcceptedFormat {
-        pattern: r"^\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}$",
-        chrono_format: "%Y-%m-%d %H:%M:%S",
+        pattern: r"^\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}(?:\.\d{1,9})?$",
+        description: "YYYY-MM-DD hh:mm:ss[.fff]",
+        chrono_format: "%Y-%m-%d %H:%M:%S%.f",
         completes_midnight: false,
+        carries_offset: false,
+        regex: OnceLock::new(),
     },
     AcceptedFormat {
-        pattern: r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}$",
-        chrono_format: "%Y-%m-%dT%H:%M:%S",
+        pattern: r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(?:\.\d{1,9})?$",
+        description: "YYYY-MM-DDThh:mm:ss[.fff]",
+        chrono_format: "%Y-%m-%dT%H:%M:%S%.f",
         completes_midnight: false,
+        carries_offset: false,
+        regex: OnceLock::new(),
     },
     AcceptedFormat {
         pattern: r"^\d{4}-\d{2}-\d{2}$",
+        description: "YYYY-MM-DD",
         chrono_format: "%Y-%m-%d %H:%M:%S",
         completes_midnight: true,
+        carries_offset: false,
+        regex: OnceLock::new(),
+    },
+    AcceptedFormat {
+        pattern: r"^\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}(?:\.\d{1,9})?(?:Z|[+-]\d{2}:\d{2})$",
+        description: "YYYY-MM-DD hh:mm:ss[.fff]+hh:mm (or Z)",
+        chrono_format: "%Y-%m-%d %H:%M:%S%.f%:z",
+        completes_midnight: false,
+        carries_offset: true,
+        regex: OnceLock::new(),
+    },
+    AcceptedFormat {
+        pattern: r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(?:\.\d{1,9})?(?:Z|[+-]\d{2}:\d{2})$",
+        description: "YYYY-MM-DDThh:mm:ss[.fff]+hh:mm (or Z)",
+        chrono_format: "%Y-%m-%dT%H:%M:%S%.f%:z",
+        completes_midnight: false,
+        carries_offset: true,
+        regex: OnceLock::new(),
     },
 ];
 
 struct AcceptedFormat {
     pattern: &'static str,
+    description: &'static str,
     chrono_format: &'static str,
     completes_midnight: bool,
+    carries_offset: bool,
+    regex: OnceLock<Regex>,
 }
 
 impl AcceptedFormat {
-    fn parse(&self, text: &str) -> Option<NaiveDateTime> {
-        let matches_pattern = regex::Regex::new(self.pattern).unwrap().is_match(text);
-        if !matches_pattern {
+    fn regex(&self) -> &Regex {
+        self.regex.get_or_init(|| Regex::new(self.pattern).unwrap())
+    }
+
+    fn parse(&self, text: &str) -> Option<ConversionTime> {
+        if !self.regex().is_match(text) {
             return None;
         }
 
+        if self.carries_offset {
+            let completed_text = match text.strip_suffix('Z') {
+                Some(without_zulu) => format!("{}+00:00", without_zulu),
+                None => text.to_string(),
+            };
+            return DateTime::parse_from_str(&completed_text, self.chrono_format)
+                .ok()
+                .map(ConversionTime::from);
+        }
+
         let completed_text = if self.completes_midnight {
             format!("{} 00:00:00", text)
         } else {
             text.to_string()
         };
 
-        NaiveDateTime::parse_from_str(&completed_text, self.chrono_format).ok()
+        NaiveDateTime::parse_from_str(&completed_text, self.chrono_format)
+            .ok()
+            .map(ConversionTime::from)
     }
 }
 
+/// Raised when the input matches none of the formats tried.
+///
+/// Lists every format that was attempted, user formats first,
+/// so the user can see what the input was compared against.
 #[derive(thiserror::Error, Debug, PartialEq, Eq)]
-#[error("Validation Error: Invalid time format found. {0} (expected: YYYY-MM-DD hh:mm:ss)")]
-pub struct ConversionTimeParseError(pub String);
+#[error(
+    "Validation Error: Invalid time format found. {input} (expected one of: {})",
+    attempted_formats.join(", ")
+)]
+pub struct ConversionTimeParseError {
+    pub input: String,
+    pub attempted_formats: Vec<String>,
+}
 
 impl FromStr for ConversionTime {
     type Err = ConversionTimeParseError;
 
     fn from_str(text: &str) -> Result<Self, Self::Err> {
-        ACCEPTED_FORMATS
-            .iter()
-            .find_map(|format| format.parse(text))
-            .map(ConversionTime)
-            .ok_or_else(|| ConversionTimeParseError(text.to_string()))
+        ConversionTime::parse_with_formats(text, &[])
+    }
+}
+
+/// Every naive date and time is a valid wall-clock time, so values
+/// computed in the domain (e.g. by `RelativeTime`) need no parsing.
+impl From<NaiveDateTime> for ConversionTime {
+    fn from(datetime: NaiveDateTime) -> Self {
+        ConversionTime {
+            datetime,
+            offset: None,
+        }
+    }
+}
+
+/// A date and time with an offset is a valid wall-clock time that
+/// keeps its offset.
+impl From<DateTime<FixedOffset>> for ConversionTime {
+    fn from(datetime: DateTime<FixedOffset>) -> Self {
+        ConversionTime {
+            datetime: datetime.naive_local(),
+            offset: Some(*datetime.offset()),
+        }
     }
 }
 
 impl ConversionTime {
+    /// Parses `text` with the user's `formats` first, then with the
+    /// built-in `ACCEPTED_FORMATS`.
+    pub fn parse_with_formats(
+        text: &str,
+        formats: &[InputFormat],
+    ) -> Result<Self, ConversionTimeParseError> {
+        formats
+            .iter()
+            .find_map(|format| format.parse(text))
+            .or_else(|| {
+                ACCEPTED_FORMATS
+                    .iter()
+                    .find_map(|format| format.parse(text))
+            })
+            .ok_or_else(|| ConversionTimeParseError {
+                input: text.to_string(),
+                attempted_formats: formats
+                    .iter()
+                    .map(|format| format.pattern().to_string())
+                    .chain(
+                        ACCEPTED_FORMATS
+                            .iter()
+                            .map(|format| format.description.to_string()),
+                    )
+                    .collect(),
+            })
+    }
+
     pub fn naive_datetime(&self) -> NaiveDateTime {
-        self.0
+        self.datetime
+    }
+
+    /// The UTC offset written in the input, if any.
+    pub fn offset(&self) -> Option<FixedOffset> {
+        self.offset
+    }
+
+    /// Finds every timestamp written in one of the `ACCEPTED_FORMATS`
+    /// inside `text`, with the byte range it occupies, left to right.
+    ///
+    /// A timestamp must stand apart from surrounding letters and digits
+    /// (`id=2024-01-01T12:00:00Z,` matches, `x2024-01-01 12:00:00`
+    /// does not). Dates without a time are not timestamps and are left
+    /// alone, as are matches that name no real date (`2024-13-01`).
+    ///
+    /// A timestamp followed by more fraction digits than it can hold
+    /// (`12:00:00.1234567890`, log4j's `12:00:00,123`), or by an offset
+    /// that does not stand apart (`12:00:00+09:00abc`, `12:00:00-0500`)
+    /// is skipped whole, rather than matched up to the separator and
+    /// read without its offset.
+    pub fn scan(text: &str) -> impl Iterator<Item = (Range<usize>, ConversionTime)> + '_ {
+        scan_regex().find_iter(text).filter_map(|found| {
+            if is_cut_short(&text[found.end()..]) {
+                return None;
+            }
+            ACCEPTED_FORMATS
+                .iter()
+                .filter(|format| !format.completes_midnight)
+                .find_map(|format| format.parse(found.as_str()))
+                .map(|time| (found.range(), time))
+        })
+    }
+}
+
+/// Whether `rest` goes on with a digit, or with a `.`, `,`, `+` or
+/// `-` and a digit, so the timestamp just before it was cut short of
+/// its fraction or offset.
+fn is_cut_short(rest: &str) -> bool {
+    let mut chars = rest.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_digit() => true,
+        Some('.' | ',' | '+' | '-') => chars.next().is_some_and(|c| c.is_ascii_digit()),
+        _ => false,
     }
 }
 
+/// The accepted formats with a time, unanchored and joined into one
+/// regex for `ConversionTime::scan`. Formats with an offset come
+/// first, so a trailing offset is taken as part of the timestamp.
+fn scan_regex() -> &'static Regex {
+    static SCAN: OnceLock<Regex> = OnceLock::new();
+    SCAN.get_or_init(|| {
+        let mut formats: Vec<&AcceptedFormat> = ACCEPTED_FORMATS
+            .iter()
+            .filter(|format| !format.completes_midnight)
+            .collect();
+        formats.sort_by_key(|format| !format.carries_offset);
+        let alternatives: Vec<String> = formats
+            .iter()
+            .map(|format| {
+                let unanchored = format.pattern.trim_start_matches('^').trim_end_matches('$');
+                format!("(?:{})", unanchored)
+            })
+            .collect();
+        Regex::new(&format!(r"\b(?:{})\b", alternatives.join("|"))).unwrap()
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::NaiveDate;
+    use chrono::{NaiveDate, Timelike};
+
+    /// Fixture: the error raised when no built-in format matches.
+    fn parse_error(input: &str) -> ConversionTimeParseError {
+        ConversionTimeParseError {
+            input: input.to_string(),
+            attempted_formats: ACCEPTED_FORMATS
+                .iter()
+                .map(|format| format.description.to_string())
+                .collect(),
+        }
+    }
 
     fn naive_datetime_of(y: i32, mo: u32, d: u32, h: u32, mi: u32, s: u32) -> NaiveDateTime {
         NaiveDate::from_ymd_opt(y, mo, d)
@@ -133,6 +325,110 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parses_rfc_3339_offset() {
+        // Arrange
+        let input = "2024-06-27T12:34:56+09:00";
+
+        // Act
+        let conversion_time: ConversionTime = input.parse().unwrap();
+
+        // Assert
+        assert_eq!(
+            conversion_time.naive_datetime(),
+            naive_datetime_of(2024, 6, 27, 12, 34, 56)
+        );
+        assert_eq!(
+            conversion_time.offset(),
+            Some(FixedOffset::east_opt(9 * 3600).unwrap())
+        );
+    }
+
+    #[test]
+    fn parses_zulu_suffix_as_utc_offset() {
+        // Arrange
+        let input = "2024-06-27 12:34:56Z";
+
+        // Act
+        let conversion_time: ConversionTime = input.parse().unwrap();
+
+        // Assert
+        assert_eq!(
+            conversion_time.offset(),
+            Some(FixedOffset::east_opt(0).unwrap())
+        );
+    }
+
+    #[test]
+    fn keeps_no_offset_for_naive_input() {
+        // Arrange
+        let input = "2024-06-27 12:34:56";
+
+        // Act
+        let conversion_time: ConversionTime = input.parse().unwrap();
+
+        // Assert
+        assert_eq!(conversion_time.offset(), None);
+    }
+
+    #[test]
+    fn rejects_offset_without_colon() {
+        // Arrange
+        let input = "2024-06-27T12:34:56+0900";
+
+        // Act
+        let result: Result<ConversionTime, ConversionTimeParseError> = input.parse();
+
+        // Assert
+        assert_eq!(result, Err(parse_error(input)));
+    }
+
+    #[test]
+    fn parses_fractional_seconds_down_to_nanoseconds() {
+        // Arrange
+        let input = "2024-06-27 12:34:56.123456789";
+
+        // Act
+        let conversion_time: ConversionTime = input.parse().unwrap();
+
+        // Assert
+        assert_eq!(
+            conversion_time.naive_datetime(),
+            naive_datetime_of(2024, 6, 27, 12, 34, 56)
+                .with_nanosecond(123_456_789)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn parses_fractional_seconds_with_offset() {
+        // Arrange
+        let input = "2024-06-27T12:34:56.789Z";
+
+        // Act
+        let conversion_time: ConversionTime = input.parse().unwrap();
+
+        // Assert
+        assert_eq!(
+            conversion_time.naive_datetime(),
+            naive_datetime_of(2024, 6, 27, 12, 34, 56)
+                .with_nanosecond(789_000_000)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_more_than_nine_fractional_digits() {
+        // Arrange
+        let input = "2024-06-27 12:34:56.1234567890";
+
+        // Act
+        let result: Result<ConversionTime, ConversionTimeParseError> = input.parse();
+
+        // Assert
+        assert_eq!(result, Err(parse_error(input)));
+    }
+
     #[test]
     fn rejects_unpadded_digits() {
         // Arrange
@@ -142,7 +438,7 @@ mod tests {
         let result: Result<ConversionTime, ConversionTimeParseError> = input.parse();
 
         // Assert
-        assert_eq!(result, Err(ConversionTimeParseError(input.to_string())));
+        assert_eq!(result, Err(parse_error(input)));
     }
 
     #[test]
@@ -154,7 +450,7 @@ mod tests {
         let result: Result<ConversionTime, ConversionTimeParseError> = input.parse();
 
         // Assert
-        assert_eq!(result, Err(ConversionTimeParseError(input.to_string())));
+        assert_eq!(result, Err(parse_error(input)));
     }
 
     #[test]
@@ -166,6 +462,108 @@ mod tests {
         let result: Result<ConversionTime, ConversionTimeParseError> = input.parse();
 
         // Assert
-        assert_eq!(result, Err(ConversionTimeParseError(input.to_string())));
+        assert_eq!(result, Err(parse_error(input)));
+    }
+
+    #[test]
+    fn parses_with_user_format() {
+        // Arrange
+        let input = "27/06/2024 12:34";
+        let formats: Vec<InputFormat> = vec!["%d/%m/%Y %H:%M".parse().unwrap()];
+
+        // Act
+        let conversion_time = ConversionTime::parse_with_formats(input, &formats).unwrap();
+
+        // Assert
+        assert_eq!(
+            conversion_time.naive_datetime(),
+            naive_datetime_of(2024, 6, 27, 12, 34, 0)
+        );
+    }
+
+    #[test]
+    fn falls_back_to_built_in_formats_after_user_formats() {
+        // Arrange
+        let input = "2024-06-27 12:34:56";
+        let formats: Vec<InputFormat> = vec!["%d/%m/%Y %H:%M".parse().unwrap()];
+
+        // Act
+        let conversion_time = ConversionTime::parse_with_formats(input, &formats).unwrap();
+
+        // Assert
+        assert_eq!(
+            conversion_time.naive_datetime(),
+            naive_datetime_of(2024, 6, 27, 12, 34, 56)
+        );
+    }
+
+    #[test]
+    fn lists_user_and_built_in_formats_on_failure() {
+        // Arrange
+        let input = "June 27th";
+        let formats: Vec<InputFormat> = vec!["%Y%m%dT%H%M%S".parse().unwrap()];
+
+        // Act
+        let result = ConversionTime::parse_with_formats(input, &formats);
+
+        // Assert
+        let attempted_formats = result.unwrap_err().attempted_formats;
+        assert_eq!(attempted_formats.len(), 1 + ACCEPTED_FORMATS.len());
+        assert_eq!(attempted_formats[0], "%Y%m%dT%H%M%S");
+    }
+
+    #[test]
+    fn scans_timestamps_inside_text() {
+        // Arrange
+        let line = "[2024-01-01 12:00:00] job=7 done at 2024-01-01T12:05:30.25+09:00.";
+
+        // Act
+        let found: Vec<(Range<usize>, ConversionTime)> = ConversionTime::scan(line).collect();
+
+        // Assert
+        assert_eq!(found.len(), 2);
+        assert_eq!(&line[found[0].0.clone()], "2024-01-01 12:00:00");
+        assert_eq!(
+            found[0].1.naive_datetime(),
+            naive_datetime_of(2024, 1, 1, 12, 0, 0)
+        );
+        assert_eq!(&line[found[1].0.clone()], "2024-01-01T12:05:30.25+09:00");
+        assert!(found[1].1.offset().is_some());
+    }
+
+    #[test]
+    fn scan_ignores_dates_and_invalid_timestamps() {
+        // Arrange
+        let line = "report 2024-01-01, bad 2024-13-01 12:00:00, glued x2024-01-01 12:00:00";
+
+        // Act
+        let found = ConversionTime::scan(line).count();
+
+        // Assert
+        assert_eq!(found, 0);
+    }
+
+    #[test]
+    fn scan_skips_timestamps_with_trailing_fraction() {
+        // Arrange
+        let line = "a 2024-01-01 12:00:00.1234567890 b 2024-01-01 12:00:00,123 c";
+
+        // Act
+        let found = ConversionTime::scan(line).count();
+
+        // Assert
+        assert_eq!(found, 0);
+    }
+
+    #[test]
+    fn scan_skips_timestamps_with_glued_offset() {
+        // Arrange
+        let line = "a 2024-06-27T12:34:56+09:00abc b 2024-06-27 12:34:56-0500 c";
+
+        // Act
+        let found = ConversionTime::scan(line).count();
+
+        // Assert
+        assert_eq!(found, 0);
     }
 }
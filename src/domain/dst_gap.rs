@@ -0,0 +1,151 @@
+use crate::domain::zone::Zone;
+use chrono::{DateTime, FixedOffset, NaiveDateTime, Offset, TimeDelta, Utc};
+use chrono_tz::GapInfo;
+use std::fmt;
+
+/// A run of local times skipped when clocks jump forward.
+///
+/// Wall times from `start` (inclusive) to `end` (exclusive) never occur
+/// in the zone. Both edges sit on the same instant, the `transition`,
+/// read with the offset before and after it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DstGap {
+    zone: Zone,
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+    offset_before: FixedOffset,
+    offset_after: FixedOffset,
+    transition: DateTime<Utc>,
+}
+
+impl DstGap {
+    /// The gap containing `wall_time` in `zone`, or `None` when the
+    /// time exists. A fixed offset has no gaps.
+    ///
+    /// The edges come from the zone's transitions on either side of
+    /// `wall_time` in the tz database.
+    pub fn find(zone: Zone, wall_time: NaiveDateTime) -> Option<DstGap> {
+        let Zone::Iana(tz) = zone else {
+            return None;
+        };
+        // begin / end are only missing at the limits of the tz data
+        let GapInfo {
+            begin: Some((start, before)),
+            end: Some(after),
+        } = GapInfo::new(&wall_time, &tz)?
+        else {
+            return None;
+        };
+
+        Some(DstGap {
+            zone,
+            start,
+            end: after.naive_local(),
+            offset_before: before.fix(),
+            offset_after: after.offset().fix(),
+            transition: after.to_utc(),
+        })
+    }
+
+    pub fn offset_before(&self) -> FixedOffset {
+        self.offset_before
+    }
+
+    pub fn offset_after(&self) -> FixedOffset {
+        self.offset_after
+    }
+
+    /// The instant the clocks jump.
+    pub fn transition(&self) -> DateTime<Utc> {
+        self.transition
+    }
+
+    /// The last whole second before the gap (01:59:59 EST).
+    pub fn nearest_before(&self) -> DateTime<Zone> {
+        (self.transition - TimeDelta::seconds(1)).with_timezone(&self.zone)
+    }
+
+    /// The first valid time after the gap (03:00:00 EDT).
+    pub fn nearest_after(&self) -> DateTime<Zone> {
+        self.transition.with_timezone(&self.zone)
+    }
+}
+
+impl fmt::Display for DstGap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Clocks in {} jump from {} to {} as the UTC offset changes from {} to {}. The nearest valid times are {} and {}.",
+            self.zone,
+            self.start,
+            self.end,
+            self.offset_before,
+            self.offset_after,
+            self.nearest_before(),
+            self.nearest_after()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeZone};
+
+    fn naive_datetime(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, mo, d)
+            .unwrap()
+            .and_hms_opt(h, mi, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn finds_spring_forward_gap() {
+        // Arrange
+        // New York skips 02:00-03:00 on 2024-03-10 (EST -> EDT)
+        let zone = Zone::Iana(chrono_tz::America::New_York);
+
+        // Act
+        let gap = DstGap::find(zone, naive_datetime(2024, 3, 10, 2, 30)).unwrap();
+
+        // Assert
+        assert_eq!(gap.offset_before().local_minus_utc(), -5 * 3600);
+        assert_eq!(gap.offset_after().local_minus_utc(), -4 * 3600);
+        assert_eq!(gap.start, naive_datetime(2024, 3, 10, 2, 0));
+        assert_eq!(gap.end, naive_datetime(2024, 3, 10, 3, 0));
+        assert_eq!(
+            gap.transition(),
+            Utc.with_ymd_and_hms(2024, 3, 10, 7, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn describes_edges_and_nearest_valid_times() {
+        // Arrange
+        let zone = Zone::Iana(chrono_tz::America::New_York);
+        let gap = DstGap::find(zone, naive_datetime(2024, 3, 10, 2, 30)).unwrap();
+
+        // Act
+        let description = gap.to_string();
+
+        // Assert
+        assert_eq!(
+            description,
+            "Clocks in America/New_York jump from 2024-03-10 02:00:00 to 2024-03-10 03:00:00 \
+             as the UTC offset changes from -05:00 to -04:00. \
+             The nearest valid times are 2024-03-10 01:59:59 EST and 2024-03-10 03:00:00 EDT."
+        );
+    }
+
+    #[test]
+    fn finds_nothing_for_existing_time() {
+        // Arrange
+        let zone = Zone::Iana(chrono_tz::America::New_York);
+
+        // Act
+        let gap = DstGap::find(zone, naive_datetime(2024, 3, 10, 3, 30));
+
+        // Assert
+        assert_eq!(gap, None);
+    }
+}
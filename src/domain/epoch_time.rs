@@ -0,0 +1,119 @@
+use crate::domain::epoch_unit::EpochUnit;
+use chrono::{DateTime, Utc};
+use std::str::FromStr;
+
+/// An instant given as a Unix epoch value (`@1719491696`).
+///
+/// Unlike `ConversionTime`, an epoch value is not a wall-clock time:
+/// it already names a single instant, independent of any timezone.
+///
+/// `FromStr` reads `@`-prefixed seconds; `parse` reads a value
+/// in any `EpochUnit`, with the `@` optional.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EpochTime(DateTime<Utc>);
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error(
+    "Validation Error: Invalid epoch time found. {0} (expected: an integer such as @1719491696)"
+)]
+pub struct EpochTimeParseError(pub String);
+
+impl FromStr for EpochTime {
+    type Err = EpochTimeParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        if !text.starts_with('@') {
+            return Err(EpochTimeParseError(text.to_string()));
+        }
+        EpochTime::parse(text, EpochUnit::Seconds)
+    }
+}
+
+/// Every UTC instant is a valid epoch time, so values computed in the
+/// domain (e.g. by `RelativeTime`) need no parsing.
+impl From<DateTime<Utc>> for EpochTime {
+    fn from(instant: DateTime<Utc>) -> Self {
+        EpochTime(instant)
+    }
+}
+
+impl EpochTime {
+    pub fn parse(text: &str, unit: EpochUnit) -> Result<Self, EpochTimeParseError> {
+        let digits = text.strip_prefix('@').unwrap_or(text);
+        // i64::from_str also takes a leading '+', which no epoch tool prints
+        if digits.starts_with('+') {
+            return Err(EpochTimeParseError(text.to_string()));
+        }
+
+        digits
+            .parse::<i64>()
+            .ok()
+            .and_then(|value| unit.instant_of(value))
+            .map(EpochTime)
+            .ok_or_else(|| EpochTimeParseError(text.to_string()))
+    }
+
+    pub fn instant(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn parses_at_prefixed_seconds() {
+        // Arrange
+        let input = "@1719491696";
+
+        // Act
+        let epoch_time: EpochTime = input.parse().unwrap();
+
+        // Assert
+        assert_eq!(
+            epoch_time.instant(),
+            Utc.with_ymd_and_hms(2024, 6, 27, 12, 34, 56).unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_seconds_without_at_prefix() {
+        // Arrange
+        let input = "1719491696";
+
+        // Act
+        let result: Result<EpochTime, EpochTimeParseError> = input.parse();
+
+        // Assert
+        assert_eq!(result, Err(EpochTimeParseError(input.to_string())));
+    }
+
+    #[test]
+    fn parses_milliseconds_without_at_prefix() {
+        // Arrange
+        let input = "1719491696789";
+
+        // Act
+        let epoch_time = EpochTime::parse(input, EpochUnit::Milliseconds).unwrap();
+
+        // Assert
+        assert_eq!(
+            epoch_time.instant(),
+            Utc.timestamp_opt(1_719_491_696, 789_000_000).unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_non_integer_value() {
+        // Arrange
+        let input = "@1719491696.5";
+
+        // Act
+        let result = EpochTime::parse(input, EpochUnit::Seconds);
+
+        // Assert
+        assert_eq!(result, Err(EpochTimeParseError(input.to_string())));
+    }
+}
@@ -0,0 +1,123 @@
+use chrono::{DateTime, TimeZone, Utc};
+use std::str::FromStr;
+
+/// The unit a Unix epoch value is counted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EpochUnit {
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error("Validation Error: Invalid epoch unit found. {0} (expected: s, ms, us, ns)")]
+pub struct EpochUnitParseError(pub String);
+
+impl FromStr for EpochUnit {
+    type Err = EpochUnitParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "s" => Ok(EpochUnit::Seconds),
+            "ms" => Ok(EpochUnit::Milliseconds),
+            "us" => Ok(EpochUnit::Microseconds),
+            "ns" => Ok(EpochUnit::Nanoseconds),
+            _ => Err(EpochUnitParseError(text.to_string())),
+        }
+    }
+}
+
+impl EpochUnit {
+    fn per_second(&self) -> i64 {
+        match self {
+            EpochUnit::Seconds => 1,
+            EpochUnit::Milliseconds => 1_000,
+            EpochUnit::Microseconds => 1_000_000,
+            EpochUnit::Nanoseconds => 1_000_000_000,
+        }
+    }
+
+    /// The instant `value` units after 1970-01-01 00:00:00 UTC.
+    ///
+    /// Returns `None` when the instant is outside chrono's range.
+    pub fn instant_of(&self, value: i64) -> Option<DateTime<Utc>> {
+        let per_second = self.per_second();
+        let seconds = value.div_euclid(per_second);
+        let nanoseconds = value.rem_euclid(per_second) * (1_000_000_000 / per_second);
+        Utc.timestamp_opt(seconds, nanoseconds as u32).single()
+    }
+
+    /// Counts the units between the epoch and `time`, truncating
+    /// toward the past.
+    ///
+    /// `i128` holds every chrono instant even in nanoseconds,
+    /// so this never overflows.
+    pub fn count_of<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> i128 {
+        let per_second = self.per_second() as i128;
+        let nanoseconds_per_unit = 1_000_000_000 / per_second;
+        time.timestamp() as i128 * per_second
+            + time.timestamp_subsec_nanos() as i128 / nanoseconds_per_unit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_unit_names() {
+        // Arrange
+        let inputs = ["s", "ms", "us", "ns"];
+
+        // Act
+        let units: Vec<EpochUnit> = inputs.iter().map(|unit| unit.parse().unwrap()).collect();
+
+        // Assert
+        assert_eq!(
+            units,
+            vec![
+                EpochUnit::Seconds,
+                EpochUnit::Milliseconds,
+                EpochUnit::Microseconds,
+                EpochUnit::Nanoseconds,
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_unit() {
+        // Arrange
+        let input = "minutes";
+
+        // Act
+        let result: Result<EpochUnit, EpochUnitParseError> = input.parse();
+
+        // Assert
+        assert_eq!(result, Err(EpochUnitParseError(input.to_string())));
+    }
+
+    #[test]
+    fn converts_negative_milliseconds_to_instant_before_epoch() {
+        // Arrange
+        let value = -1_500;
+
+        // Act
+        let instant = EpochUnit::Milliseconds.instant_of(value).unwrap();
+
+        // Assert
+        assert_eq!(instant, Utc.timestamp_opt(-2, 500_000_000).unwrap());
+    }
+
+    #[test]
+    fn counts_microseconds_since_epoch() {
+        // Arrange
+        let time = Utc.timestamp_opt(1_719_491_696, 789_123_456).unwrap();
+
+        // Act
+        let count = EpochUnit::Microseconds.count_of(&time);
+
+        // Assert
+        assert_eq!(count, 1_719_491_696_789_123);
+    }
+}
@@ -0,0 +1,96 @@
+use std::str::FromStr;
+
+/// Strategy to resolve a local time that does not exist.
+///
+/// When DST starts, clocks jump forward and the skipped local times
+/// never occur (the gap). This strategy decides whether that is an
+/// error, or which instant stands in for the missing time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GapTimeStrategy {
+    /// Fail with `TranslationError::NonexistentTime`.
+    #[default]
+    Error,
+    /// The first instant after the gap (02:30 -> 03:00).
+    ShiftForward,
+    /// The last whole second before the gap (02:30 -> 01:59:59), as
+    /// `DstGap::nearest_before` names it.
+    ShiftBackward,
+    /// Read the time with the offset in effect before the gap
+    /// (02:30 at UTC-5 -> 03:30 at UTC-4).
+    PreTransitionOffset,
+    /// Read the time with the offset in effect after the gap
+    /// (02:30 at UTC-4 -> 01:30 at UTC-5).
+    PostTransitionOffset,
+}
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error(
+    "Validation Error: Invalid gap strategy found. {0} (expected: error, shift-forward, shift-backward, pre-transition-offset, post-transition-offset)"
+)]
+pub struct GapTimeStrategyParseError(pub String);
+
+impl FromStr for GapTimeStrategy {
+    type Err = GapTimeStrategyParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "error" => Ok(GapTimeStrategy::Error),
+            "shift-forward" => Ok(GapTimeStrategy::ShiftForward),
+            "shift-backward" => Ok(GapTimeStrategy::ShiftBackward),
+            "pre-transition-offset" => Ok(GapTimeStrategy::PreTransitionOffset),
+            "post-transition-offset" => Ok(GapTimeStrategy::PostTransitionOffset),
+            _ => Err(GapTimeStrategyParseError(text.to_string())),
+        }
+    }
+}
+
+impl GapTimeStrategy {
+    /// The name `FromStr` accepts for this strategy.
+    pub fn name(&self) -> &'static str {
+        match self {
+            GapTimeStrategy::Error => "error",
+            GapTimeStrategy::ShiftForward => "shift-forward",
+            GapTimeStrategy::ShiftBackward => "shift-backward",
+            GapTimeStrategy::PreTransitionOffset => "pre-transition-offset",
+            GapTimeStrategy::PostTransitionOffset => "post-transition-offset",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_every_strategy_name() {
+        // Arrange
+        let strategies = [
+            GapTimeStrategy::Error,
+            GapTimeStrategy::ShiftForward,
+            GapTimeStrategy::ShiftBackward,
+            GapTimeStrategy::PreTransitionOffset,
+            GapTimeStrategy::PostTransitionOffset,
+        ];
+
+        // Act
+        let parsed: Vec<GapTimeStrategy> = strategies
+            .iter()
+            .map(|strategy| strategy.name().parse().unwrap())
+            .collect();
+
+        // Assert
+        assert_eq!(parsed, strategies);
+    }
+
+    #[test]
+    fn rejects_unknown_strategy() {
+        // Arrange
+        let input = "shift";
+
+        // Act
+        let result: Result<GapTimeStrategy, GapTimeStrategyParseError> = input.parse();
+
+        // Assert
+        assert_eq!(result, Err(GapTimeStrategyParseError(input.to_string())));
+    }
+}
@@ -0,0 +1,151 @@
+use crate::domain::conversion_time::ConversionTime;
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
+use std::str::FromStr;
+
+/// A user-supplied chrono strftime pattern for reading `--time`
+/// (e.g. `%d/%m/%Y %H:%M`).
+///
+/// `FromStr` rejects patterns chrono cannot interpret, so a typo in the
+/// pattern is reported up front instead of as "no format matched".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputFormat(String);
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error("Validation Error: Invalid input format found. {0} (expected: a chrono strftime pattern such as %d/%m/%Y %H:%M)")]
+pub struct InputFormatParseError(pub String);
+
+impl FromStr for InputFormat {
+    type Err = InputFormatParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let is_valid =
+            !text.is_empty() && StrftimeItems::new(text).all(|item| !matches!(item, Item::Error));
+        if !is_valid {
+            return Err(InputFormatParseError(text.to_string()));
+        }
+        Ok(InputFormat(text.to_string()))
+    }
+}
+
+impl InputFormat {
+    pub fn pattern(&self) -> &str {
+        &self.0
+    }
+
+    /// Reads `text` with this pattern.
+    ///
+    /// A pattern with an offset (`%z`) keeps it; a pattern with only
+    /// a date is completed with midnight, like the built-in formats.
+    pub fn parse(&self, text: &str) -> Option<ConversionTime> {
+        DateTime::parse_from_str(text, &self.0)
+            .map(ConversionTime::from)
+            .or_else(|_| NaiveDateTime::parse_from_str(text, &self.0).map(ConversionTime::from))
+            .or_else(|_| {
+                NaiveDate::parse_from_str(text, &self.0)
+                    .map(|date| ConversionTime::from(date.and_time(NaiveTime::MIN)))
+            })
+            .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{FixedOffset, NaiveDate};
+
+    fn parse(pattern: &str, text: &str) -> Option<ConversionTime> {
+        pattern.parse::<InputFormat>().unwrap().parse(text)
+    }
+
+    fn naive_datetime_of(y: i32, mo: u32, d: u32, h: u32, mi: u32, s: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, mo, d)
+            .unwrap()
+            .and_hms_opt(h, mi, s)
+            .unwrap()
+    }
+
+    #[test]
+    fn rejects_unknown_specifier() {
+        // Arrange
+        let input = "%Y-%m-%d %Q";
+
+        // Act
+        let result: Result<InputFormat, InputFormatParseError> = input.parse();
+
+        // Assert
+        assert_eq!(result, Err(InputFormatParseError(input.to_string())));
+    }
+
+    #[test]
+    fn reads_month_name_layout() {
+        // Arrange
+        let text = "Jun 27 2024 12:34:56";
+
+        // Act
+        let conversion_time = parse("%b %d %Y %H:%M:%S", text).unwrap();
+
+        // Assert
+        assert_eq!(
+            conversion_time.naive_datetime(),
+            naive_datetime_of(2024, 6, 27, 12, 34, 56)
+        );
+    }
+
+    #[test]
+    fn reads_compact_basic_layout() {
+        // Arrange
+        let text = "20240627T123456";
+
+        // Act
+        let conversion_time = parse("%Y%m%dT%H%M%S", text).unwrap();
+
+        // Assert
+        assert_eq!(
+            conversion_time.naive_datetime(),
+            naive_datetime_of(2024, 6, 27, 12, 34, 56)
+        );
+    }
+
+    #[test]
+    fn keeps_offset_read_by_pattern() {
+        // Arrange
+        let text = "27/06/2024 12:34 +0530";
+
+        // Act
+        let conversion_time = parse("%d/%m/%Y %H:%M %z", text).unwrap();
+
+        // Assert
+        assert_eq!(
+            conversion_time.offset(),
+            Some(FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap())
+        );
+    }
+
+    #[test]
+    fn completes_date_only_pattern_with_midnight() {
+        // Arrange
+        let text = "27.06.2024";
+
+        // Act
+        let conversion_time = parse("%d.%m.%Y", text).unwrap();
+
+        // Assert
+        assert_eq!(
+            conversion_time.naive_datetime(),
+            naive_datetime_of(2024, 6, 27, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn does_not_match_other_layout() {
+        // Arrange
+        let text = "2024-06-27 12:34:56";
+
+        // Act
+        let conversion_time = parse("%d/%m/%Y %H:%M", text);
+
+        // Assert
+        assert_eq!(conversion_time, None);
+    }
+}
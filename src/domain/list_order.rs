@@ -0,0 +1,56 @@
+use std::str::FromStr;
+
+/// The order of the timezones printed by `tzt list`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ListOrder {
+    /// Alphabetically by IANA name.
+    #[default]
+    Name,
+    /// By current UTC offset, west to east; by name within an offset.
+    Offset,
+}
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error("Validation Error: Invalid sort order found. {0} (expected: name, offset)")]
+pub struct ListOrderParseError(pub String);
+
+impl FromStr for ListOrder {
+    type Err = ListOrderParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "name" => Ok(ListOrder::Name),
+            "offset" => Ok(ListOrder::Offset),
+            _ => Err(ListOrderParseError(text.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_order_names() {
+        // Arrange
+        let inputs = ["name", "offset"];
+
+        // Act
+        let orders: Vec<ListOrder> = inputs.iter().map(|name| name.parse().unwrap()).collect();
+
+        // Assert
+        assert_eq!(orders, vec![ListOrder::Name, ListOrder::Offset]);
+    }
+
+    #[test]
+    fn rejects_unknown_order() {
+        // Arrange
+        let input = "abbreviation";
+
+        // Act
+        let result: Result<ListOrder, ListOrderParseError> = input.parse();
+
+        // Assert
+        assert_eq!(result, Err(ListOrderParseError(input.to_string())));
+    }
+}
@@ -0,0 +1,87 @@
+use crate::domain::list_order::ListOrder;
+use crate::domain::preferred_region::PreferredRegion;
+use crate::domain::utc_offset::UtcOffset;
+use chrono::FixedOffset;
+use chrono_tz::Tz;
+
+/// Which IANA timezones `tzt list` prints, and in what order.
+///
+/// Every filter is optional; a timezone is listed only when it passes
+/// all of those given. With none, every timezone is listed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListRequest {
+    pattern: Option<String>,
+    region: Option<PreferredRegion>,
+    offset: Option<UtcOffset>,
+    order: ListOrder,
+}
+
+impl ListRequest {
+    /// Keeps the timezones whose name contains `pattern`, ignoring case.
+    pub fn with_pattern(self, pattern: Option<String>) -> Self {
+        Self { pattern, ..self }
+    }
+
+    /// Keeps the timezones of one IANA region (`Asia/...`).
+    pub fn with_region(self, region: Option<PreferredRegion>) -> Self {
+        Self { region, ..self }
+    }
+
+    /// Keeps the timezones currently at this UTC offset.
+    pub fn with_offset(self, offset: Option<UtcOffset>) -> Self {
+        Self { offset, ..self }
+    }
+
+    pub fn with_order(self, order: ListOrder) -> Self {
+        Self { order, ..self }
+    }
+
+    /// Whether `zone`, currently at `current_offset`, passes every filter.
+    pub fn admits(&self, zone: Tz, current_offset: FixedOffset) -> bool {
+        let name = zone.name();
+        self.pattern.as_ref().is_none_or(|pattern| {
+            name.to_ascii_lowercase()
+                .contains(&pattern.to_ascii_lowercase())
+        }) && self.region.is_none_or(|region| region.contains(name))
+            && self
+                .offset
+                .is_none_or(|offset| offset.offset() == current_offset)
+    }
+
+    pub fn order(&self) -> ListOrder {
+        self.order
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hours(hours: i32) -> FixedOffset {
+        FixedOffset::east_opt(hours * 3600).unwrap()
+    }
+
+    #[test]
+    fn admits_everything_without_filters() {
+        // Arrange
+        let request = ListRequest::default();
+
+        // Act & Assert
+        assert!(request.admits(chrono_tz::Asia::Tokyo, hours(9)));
+        assert!(request.admits(chrono_tz::Etc::UTC, hours(0)));
+    }
+
+    #[test]
+    fn combines_filters() {
+        // Arrange
+        let request = ListRequest::default()
+            .with_pattern(Some("TOK".to_string()))
+            .with_region(Some("Asia".parse().unwrap()))
+            .with_offset(Some("+09:00".parse().unwrap()));
+
+        // Act & Assert
+        assert!(request.admits(chrono_tz::Asia::Tokyo, hours(9)));
+        assert!(!request.admits(chrono_tz::Asia::Tokyo, hours(8)));
+        assert!(!request.admits(chrono_tz::Asia::Seoul, hours(9)));
+    }
+}
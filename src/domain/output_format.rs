@@ -0,0 +1,117 @@
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, TimeZone, Utc};
+use std::fmt::{Display, Write};
+use std::str::FromStr;
+
+/// A user-supplied chrono strftime pattern for printing the result
+/// (e.g. `%Y%m%dT%H%M%S`).
+///
+/// `FromStr` renders a sample instant with the pattern, so a pattern
+/// chrono cannot print is rejected up front rather than failing when
+/// the result is written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputFormat(String);
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error("Validation Error: Invalid output format found. {0} (expected: a chrono strftime pattern such as %Y-%m-%dT%H:%M:%S%:z)")]
+pub struct OutputFormatParseError(pub String);
+
+/// Raised when a pattern that printed the sample instant cannot print
+/// a particular time.
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error("Output Error: {time} cannot be printed with the output format {format}.")]
+pub struct OutputFormatError {
+    pub format: String,
+    pub time: String,
+}
+
+impl FromStr for OutputFormat {
+    type Err = OutputFormatParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let has_valid_items =
+            !text.is_empty() && StrftimeItems::new(text).all(|item| !matches!(item, Item::Error));
+        let format = OutputFormat(text.to_string());
+        if !has_valid_items || format.format(&Utc.timestamp_opt(0, 0).unwrap()).is_err() {
+            return Err(OutputFormatParseError(text.to_string()));
+        }
+        Ok(format)
+    }
+}
+
+impl OutputFormat {
+    /// Renders `time` with this pattern.
+    ///
+    /// `FromStr` only proved that the pattern prints one UTC sample, so
+    /// printing another time (in another zone) can still fail.
+    pub fn format<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Result<String, OutputFormatError>
+    where
+        Tz::Offset: Display,
+    {
+        let mut output = String::new();
+        write!(output, "{}", time.format(&self.0)).map_err(|_| OutputFormatError {
+            format: self.0.clone(),
+            time: format!("{:?}", time),
+        })?;
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_time() -> DateTime<chrono_tz::Tz> {
+        chrono_tz::Asia::Tokyo
+            .with_ymd_and_hms(2024, 1, 1, 21, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn formats_compact_timestamp() {
+        // Arrange
+        let format: OutputFormat = "%Y%m%dT%H%M%S".parse().unwrap();
+
+        // Act
+        let output = format.format(&sample_time()).unwrap();
+
+        // Assert
+        assert_eq!(output, "20240101T210000");
+    }
+
+    #[test]
+    fn formats_weekday_and_abbreviation() {
+        // Arrange
+        let format: OutputFormat = "%a %d %b %H:%M %Z".parse().unwrap();
+
+        // Act
+        let output = format.format(&sample_time()).unwrap();
+
+        // Assert
+        assert_eq!(output, "Mon 01 Jan 21:00 JST");
+    }
+
+    #[test]
+    fn rejects_unknown_specifier() {
+        // Arrange
+        let input = "%Y-%Q";
+
+        // Act
+        let result: Result<OutputFormat, OutputFormatParseError> = input.parse();
+
+        // Assert
+        assert_eq!(result, Err(OutputFormatParseError(input.to_string())));
+    }
+
+    #[test]
+    fn rejects_empty_pattern() {
+        // Arrange
+        let input = "";
+
+        // Act
+        let result: Result<OutputFormat, OutputFormatParseError> = input.parse();
+
+        // Assert
+        assert_eq!(result, Err(OutputFormatParseError(input.to_string())));
+    }
+}
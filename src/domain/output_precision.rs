@@ -0,0 +1,107 @@
+use std::str::FromStr;
+
+/// Number of fractional-second digits to print (0 to 9).
+///
+/// Without a precision the output shows only as many digits as the
+/// value needs; with one, it always shows exactly that many, so
+/// microsecond input round-trips as microseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutputPrecision(usize);
+
+const MAX_DIGITS: usize = 9;
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error("Validation Error: Invalid output precision found. {0} (expected: an integer from 0 to 9)")]
+pub struct OutputPrecisionParseError(pub String);
+
+impl FromStr for OutputPrecision {
+    type Err = OutputPrecisionParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        text.parse::<usize>()
+            .ok()
+            .filter(|digits| *digits <= MAX_DIGITS)
+            .map(OutputPrecision)
+            .ok_or_else(|| OutputPrecisionParseError(text.to_string()))
+    }
+}
+
+impl OutputPrecision {
+    /// Renders the fraction of a second, dot included, at this precision.
+    ///
+    /// Extra digits are truncated, never rounded, so the printed time
+    /// is never later than the real one. A leap second (chrono encodes
+    /// it as 1_000_000_000 or more nanoseconds) keeps its sub-second part.
+    pub fn fraction_of(&self, nanoseconds: u32) -> String {
+        if self.0 == 0 {
+            return String::new();
+        }
+        let all_digits = format!("{:09}", nanoseconds % 1_000_000_000);
+        format!(".{}", &all_digits[..self.0])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_digits_in_range() {
+        // Arrange
+        let input = "6";
+
+        // Act
+        let precision: OutputPrecision = input.parse().unwrap();
+
+        // Assert
+        assert_eq!(precision, OutputPrecision(6));
+    }
+
+    #[test]
+    fn rejects_more_than_nine_digits() {
+        // Arrange
+        let input = "10";
+
+        // Act
+        let result: Result<OutputPrecision, OutputPrecisionParseError> = input.parse();
+
+        // Assert
+        assert_eq!(result, Err(OutputPrecisionParseError(input.to_string())));
+    }
+
+    #[test]
+    fn pads_fraction_with_trailing_zeros() {
+        // Arrange
+        let precision: OutputPrecision = "6".parse().unwrap();
+
+        // Act
+        let fraction = precision.fraction_of(789_000_000);
+
+        // Assert
+        assert_eq!(fraction, ".789000");
+    }
+
+    #[test]
+    fn truncates_fraction_beyond_precision() {
+        // Arrange
+        let precision: OutputPrecision = "3".parse().unwrap();
+
+        // Act
+        let fraction = precision.fraction_of(123_999_999);
+
+        // Assert
+        assert_eq!(fraction, ".123");
+    }
+
+    #[test]
+    fn omits_fraction_at_zero_precision() {
+        // Arrange
+        let precision: OutputPrecision = "0".parse().unwrap();
+
+        // Act
+        let fraction = precision.fraction_of(123_456_789);
+
+        // Assert
+        assert_eq!(fraction, "");
+    }
+}
@@ -0,0 +1,212 @@
+use chrono_tz::Tz;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Countries: code, name, extra names, zones. Compiled into the binary.
+const COUNTRIES: &str = include_str!("data/countries.tsv");
+
+/// Cities that do not name an IANA zone themselves.
+const CITIES: &str = include_str!("data/cities.tsv");
+
+/// The result of looking up a city or country.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlaceLookup {
+    /// The place lies in one zone.
+    Resolved(Tz),
+    /// The place is a country spanning several zones.
+    SeveralZones(Vec<Tz>),
+    /// No known place has this name.
+    Unknown,
+}
+
+/// Looks up a city (`Bangalore`, `São Paulo`) or a country, by ISO 3166
+/// code (`JP`) or English name (`Japan`).
+///
+/// Names match regardless of case, accents, and `_` / `-` / spaces.
+pub fn lookup_place(text: &str) -> PlaceLookup {
+    match place_index().get(&place_key(text)) {
+        Some(zones) if zones.len() == 1 => PlaceLookup::Resolved(zones[0]),
+        Some(zones) => PlaceLookup::SeveralZones(zones.clone()),
+        None => PlaceLookup::Unknown,
+    }
+}
+
+/// Normalized place name -> zones, built once from the embedded data.
+///
+/// Every zone a country lists also makes its city part a place name
+/// (`America/Sao_Paulo` -> `sao paulo`). Entries added earlier win,
+/// so country codes and names shadow cities spelled the same way.
+fn place_index() -> &'static HashMap<String, Vec<Tz>> {
+    static INDEX: OnceLock<HashMap<String, Vec<Tz>>> = OnceLock::new();
+    INDEX.get_or_init(|| {
+        let mut index: HashMap<String, Vec<Tz>> = HashMap::new();
+        let mut zone_cities: Vec<(String, Tz)> = Vec::new();
+
+        for [code, name, extra_names, zones] in rows::<4>(COUNTRIES) {
+            let zones: Vec<Tz> = zones.split(',').map(parse_zone).collect();
+            zone_cities.extend(zones.iter().map(|zone| (city_of(*zone), *zone)));
+            for country in [code, name].into_iter().chain(extra_names.split(',')) {
+                if !country.is_empty() {
+                    index.entry(place_key(country)).or_insert(zones.clone());
+                }
+            }
+        }
+        for [city, zone] in rows::<2>(CITIES) {
+            index
+                .entry(place_key(city))
+                .or_insert(vec![parse_zone(zone)]);
+        }
+        for (city, zone) in zone_cities {
+            index.entry(place_key(&city)).or_insert(vec![zone]);
+        }
+
+        index
+    })
+}
+
+/// Data lines split on tabs, skipping comments. Panics on a malformed
+/// line: the data is compiled in, so that is a bug caught by the tests.
+fn rows<const N: usize>(data: &'static str) -> impl Iterator<Item = [&'static str; N]> {
+    data.lines()
+        .filter(|line| !line.starts_with('#') && !line.is_empty())
+        .map(|line| {
+            let fields: Vec<&str> = line.split('\t').collect();
+            fields
+                .try_into()
+                .unwrap_or_else(|_| panic!("malformed place data line: {line}"))
+        })
+}
+
+fn parse_zone(name: &str) -> Tz {
+    name.parse()
+        .unwrap_or_else(|_| panic!("place data names unknown zone: {name}"))
+}
+
+fn city_of(zone: Tz) -> String {
+    zone.name()
+        .rsplit('/')
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Lowercases, drops accents and punctuation noise, and collapses
+/// `_`, `-` and runs of spaces into one space.
+fn place_key(text: &str) -> String {
+    let folded: String = text
+        .chars()
+        .flat_map(char::to_lowercase)
+        .map(fold_accent)
+        .map(|c| if c == '_' || c == '-' { ' ' } else { c })
+        .filter(|c| !matches!(c, '.' | '\'' | '’'))
+        .collect();
+    folded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Maps a lowercase Latin letter with a diacritic to its base letter.
+fn fold_accent(c: char) -> char {
+    match c {
+        'á' | 'à' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+        'ç' => 'c',
+        'é' | 'è' | 'ê' | 'ë' => 'e',
+        'í' | 'ì' | 'î' | 'ï' => 'i',
+        'ñ' => 'n',
+        'ó' | 'ò' | 'ô' | 'õ' | 'ö' | 'ø' => 'o',
+        'ú' | 'ù' | 'û' | 'ü' => 'u',
+        'ý' | 'ÿ' => 'y',
+        _ => c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_city_without_own_zone() {
+        // Arrange
+        let input = "Bangalore";
+
+        // Act
+        let lookup = lookup_place(input);
+
+        // Assert
+        assert_eq!(lookup, PlaceLookup::Resolved(chrono_tz::Asia::Kolkata));
+    }
+
+    #[test]
+    fn resolves_zone_city_written_with_accent_and_space() {
+        // Arrange
+        let input = "São Paulo";
+
+        // Act
+        let lookup = lookup_place(input);
+
+        // Assert
+        assert_eq!(lookup, PlaceLookup::Resolved(chrono_tz::America::Sao_Paulo));
+    }
+
+    #[test]
+    fn resolves_single_zone_country_by_code() {
+        // Arrange
+        let input = "jp";
+
+        // Act
+        let lookup = lookup_place(input);
+
+        // Assert
+        assert_eq!(lookup, PlaceLookup::Resolved(chrono_tz::Asia::Tokyo));
+    }
+
+    #[test]
+    fn resolves_single_zone_country_by_name() {
+        // Arrange
+        let input = "United Kingdom";
+
+        // Act
+        let lookup = lookup_place(input);
+
+        // Assert
+        assert_eq!(lookup, PlaceLookup::Resolved(chrono_tz::Europe::London));
+    }
+
+    #[test]
+    fn lists_zones_of_country_spanning_several() {
+        // Arrange
+        let input = "US";
+
+        // Act
+        let lookup = lookup_place(input);
+
+        // Assert
+        let PlaceLookup::SeveralZones(zones) = lookup else {
+            panic!("US must span several zones, got {:?}", lookup);
+        };
+        assert_eq!(zones.first(), Some(&chrono_tz::America::New_York));
+        assert!(zones.contains(&chrono_tz::Pacific::Honolulu));
+    }
+
+    #[test]
+    fn reports_unknown_place() {
+        // Arrange
+        let input = "Atlantis";
+
+        // Act
+        let lookup = lookup_place(input);
+
+        // Assert
+        assert_eq!(lookup, PlaceLookup::Unknown);
+    }
+
+    #[test]
+    fn embedded_data_names_only_known_zones() {
+        // Arrange
+        // place_index() panics on a zone chrono-tz does not know
+
+        // Act
+        let index = place_index();
+
+        // Assert
+        assert!(!index.is_empty());
+    }
+}
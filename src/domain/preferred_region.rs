@@ -0,0 +1,88 @@
+use std::str::FromStr;
+
+/// The IANA areas: the part of a zone name before the first `/`.
+pub(crate) const REGIONS: [&str; 10] = [
+    "Africa",
+    "America",
+    "Antarctica",
+    "Arctic",
+    "Asia",
+    "Atlantic",
+    "Australia",
+    "Europe",
+    "Indian",
+    "Pacific",
+];
+
+/// The IANA area to favor when an abbreviation names several offsets
+/// (e.g. `Europe` turns `IST` into Irish Standard Time).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PreferredRegion(&'static str);
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error("Validation Error: Invalid region found. {0} (expected: Africa, America, Antarctica, Arctic, Asia, Atlantic, Australia, Europe, Indian, Pacific)")]
+pub struct PreferredRegionParseError(pub String);
+
+impl FromStr for PreferredRegion {
+    type Err = PreferredRegionParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        REGIONS
+            .iter()
+            .find(|region| region.eq_ignore_ascii_case(text))
+            .map(|region| PreferredRegion(region))
+            .ok_or_else(|| PreferredRegionParseError(text.to_string()))
+    }
+}
+
+impl PreferredRegion {
+    /// Whether the IANA zone name lies in this region.
+    pub fn contains(&self, zone_name: &str) -> bool {
+        zone_name
+            .strip_prefix(self.0)
+            .is_some_and(|rest| rest.starts_with('/'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_region_case_insensitively() {
+        // Arrange
+        let input = "europe";
+
+        // Act
+        let region: PreferredRegion = input.parse().unwrap();
+
+        // Assert
+        assert_eq!(region, PreferredRegion("Europe"));
+    }
+
+    #[test]
+    fn rejects_unknown_region() {
+        // Arrange
+        let input = "Atlantis";
+
+        // Act
+        let result: Result<PreferredRegion, PreferredRegionParseError> = input.parse();
+
+        // Assert
+        assert_eq!(result, Err(PreferredRegionParseError(input.to_string())));
+    }
+
+    #[test]
+    fn contains_zones_under_its_area_only() {
+        // Arrange
+        let region: PreferredRegion = "America".parse().unwrap();
+
+        // Act
+        let contains_new_york = region.contains("America/New_York");
+        let contains_lookalike = region.contains("Americana/Nowhere");
+
+        // Assert
+        assert!(contains_new_york);
+        assert!(!contains_lookalike);
+    }
+}
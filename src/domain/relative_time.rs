@@ -0,0 +1,352 @@
+use crate::domain::clock::Clock;
+use crate::domain::conversion_time::ConversionTime;
+use crate::domain::epoch_time::EpochTime;
+use crate::domain::requested_time::RequestedTime;
+use crate::domain::zone::Zone;
+use chrono::{Datelike, NaiveTime, TimeDelta, Weekday};
+use std::str::FromStr;
+
+/// A time given relative to "now" (`now + 3h`, `tomorrow 09:00`).
+///
+/// Parsing is pure: the expression is only turned into a concrete
+/// time by `resolve`, against a `Clock` and the source timezone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelativeTime(RelativeExpression);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RelativeExpression {
+    /// An instant: now, shifted by a duration (`now`, `in 90 minutes`).
+    Shifted(TimeDelta),
+    /// A wall-clock time on a day counted from today in the source
+    /// timezone (`today`, `next monday 14:30`).
+    OnDay {
+        day: DayAnchor,
+        time_of_day: NaiveTime,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DayAnchor {
+    DaysFromToday(i64),
+    NextWeekday(Weekday),
+}
+
+/// Words that can open a relative expression.
+const LEADING_KEYWORDS: [&str; 6] = ["now", "in", "today", "tomorrow", "yesterday", "next"];
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error("Validation Error: Invalid relative time found. {0} (expected: now, today, tomorrow 09:00, next monday 14:30, now + 3h, in 90 minutes)")]
+pub struct RelativeTimeParseError(pub String);
+
+impl FromStr for RelativeTime {
+    type Err = RelativeTimeParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        // "now+3h" and "now + 3h" tokenize the same way
+        let normalized: String = text.to_lowercase().replace('+', " + ").replace('-', " - ");
+        let tokens: Vec<&str> = normalized.split_whitespace().collect();
+
+        parse_expression(&tokens)
+            .map(RelativeTime)
+            .ok_or_else(|| RelativeTimeParseError(text.to_string()))
+    }
+}
+
+impl RelativeTime {
+    /// Whether `text` opens with a relative keyword, i.e. whether a
+    /// parse failure should be reported as a bad relative expression
+    /// rather than a bad absolute time.
+    pub fn looks_relative(text: &str) -> bool {
+        let first_word: String = text
+            .trim_start()
+            .chars()
+            .take_while(|c| c.is_alphabetic())
+            .collect::<String>()
+            .to_lowercase();
+        LEADING_KEYWORDS.contains(&first_word.as_str())
+    }
+
+    /// Pins the expression to a concrete time.
+    ///
+    /// Day-based expressions become a wall-clock time in `timezone`,
+    /// so DST rules still apply to them; shifts from now become an
+    /// instant. Returns `None` when the result is outside chrono's range.
+    pub fn resolve(&self, clock: &impl Clock, timezone: Zone) -> Option<RequestedTime> {
+        let now = clock.now();
+        match self.0 {
+            RelativeExpression::Shifted(shift) => now
+                .checked_add_signed(shift)
+                .map(|instant| EpochTime::from(instant).into()),
+            RelativeExpression::OnDay { day, time_of_day } => {
+                let today = now.with_timezone(&timezone).date_naive();
+                let days_ahead = match day {
+                    DayAnchor::DaysFromToday(days) => days,
+                    DayAnchor::NextWeekday(weekday) => days_until_next(today.weekday(), weekday),
+                };
+                today
+                    .checked_add_signed(TimeDelta::try_days(days_ahead)?)
+                    .map(|date| ConversionTime::from(date.and_time(time_of_day)).into())
+            }
+        }
+    }
+}
+
+fn parse_expression(tokens: &[&str]) -> Option<RelativeExpression> {
+    match tokens {
+        ["now"] => Some(RelativeExpression::Shifted(TimeDelta::zero())),
+        ["now", "+", duration @ ..] => parse_duration(duration).map(RelativeExpression::Shifted),
+        ["now", "-", duration @ ..] => {
+            parse_duration(duration).map(|shift| RelativeExpression::Shifted(-shift))
+        }
+        ["in", duration @ ..] => parse_duration(duration).map(RelativeExpression::Shifted),
+        ["next", weekday, time_of_day @ ..] => {
+            let weekday = weekday.parse::<Weekday>().ok()?;
+            on_day(DayAnchor::NextWeekday(weekday), time_of_day)
+        }
+        [day, time_of_day @ ..] => {
+            let days = match *day {
+                "yesterday" => -1,
+                "today" => 0,
+                "tomorrow" => 1,
+                _ => return None,
+            };
+            on_day(DayAnchor::DaysFromToday(days), time_of_day)
+        }
+        [] => None,
+    }
+}
+
+/// Builds a day-based expression; the time of day defaults to midnight.
+fn on_day(day: DayAnchor, time_of_day: &[&str]) -> Option<RelativeExpression> {
+    let time_of_day = match time_of_day {
+        [] => NaiveTime::MIN,
+        [text] => parse_time_of_day(text)?,
+        _ => return None,
+    };
+    Some(RelativeExpression::OnDay { day, time_of_day })
+}
+
+fn parse_time_of_day(text: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(text, "%H:%M:%S")
+        .or_else(|_| NaiveTime::parse_from_str(text, "%H:%M"))
+        .ok()
+}
+
+/// Parses one or more `<amount><unit>` terms (`3h`, `1 hour 30 min`).
+fn parse_duration(tokens: &[&str]) -> Option<TimeDelta> {
+    let mut total = TimeDelta::zero();
+    let mut rest = tokens;
+    if rest.is_empty() {
+        return None;
+    }
+
+    while let [first, tail @ ..] = rest {
+        let digits_end = first
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(first.len());
+        let amount: i64 = first[..digits_end].parse().ok()?;
+        let (unit, tail) = match &first[digits_end..] {
+            "" => tail.split_first().map(|(unit, tail)| (*unit, tail))?,
+            unit => (unit, tail),
+        };
+        total = total.checked_add(&duration_of(amount, unit)?)?;
+        rest = tail;
+    }
+
+    Some(total)
+}
+
+fn duration_of(amount: i64, unit: &str) -> Option<TimeDelta> {
+    match unit {
+        "s" | "sec" | "secs" | "second" | "seconds" => TimeDelta::try_seconds(amount),
+        "m" | "min" | "mins" | "minute" | "minutes" => TimeDelta::try_minutes(amount),
+        "h" | "hr" | "hrs" | "hour" | "hours" => TimeDelta::try_hours(amount),
+        "d" | "day" | "days" => TimeDelta::try_days(amount),
+        "w" | "week" | "weeks" => TimeDelta::try_weeks(amount),
+        _ => None,
+    }
+}
+
+/// Days from `today` to the next `target`, always 1 to 7:
+/// "next monday" said on a Monday means a week later.
+fn days_until_next(today: Weekday, target: Weekday) -> i64 {
+    let days = (target.num_days_from_monday() + 7 - today.num_days_from_monday()) % 7;
+    if days == 0 {
+        7
+    } else {
+        days as i64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::FixedClock;
+    use chrono::{NaiveDate, NaiveDateTime, TimeZone, Utc};
+
+    fn wall_clock(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> RequestedTime {
+        let naive: NaiveDateTime = NaiveDate::from_ymd_opt(y, mo, d)
+            .unwrap()
+            .and_hms_opt(h, mi, 0)
+            .unwrap();
+        ConversionTime::from(naive).into()
+    }
+
+    fn instant(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> RequestedTime {
+        EpochTime::from(Utc.with_ymd_and_hms(y, mo, d, h, mi, 0).unwrap()).into()
+    }
+
+    fn resolve(input: &str, timezone: chrono_tz::Tz) -> RequestedTime {
+        let relative: RelativeTime = input.parse().unwrap();
+        relative
+            .resolve(&FixedClock::fixture(), Zone::Iana(timezone))
+            .unwrap()
+    }
+
+    #[test]
+    fn resolves_now_to_current_instant() {
+        // Arrange
+        let input = "now";
+
+        // Act
+        let resolved = resolve(input, chrono_tz::UTC);
+
+        // Assert
+        assert_eq!(resolved, instant(2024, 6, 27, 12, 0));
+    }
+
+    #[test]
+    fn shifts_now_forward_by_hours() {
+        // Arrange
+        let input = "now + 3h";
+
+        // Act
+        let resolved = resolve(input, chrono_tz::UTC);
+
+        // Assert
+        assert_eq!(resolved, instant(2024, 6, 27, 15, 0));
+    }
+
+    #[test]
+    fn shifts_now_backward_without_spaces() {
+        // Arrange
+        let input = "now-1d";
+
+        // Act
+        let resolved = resolve(input, chrono_tz::UTC);
+
+        // Assert
+        assert_eq!(resolved, instant(2024, 6, 26, 12, 0));
+    }
+
+    #[test]
+    fn shifts_now_by_spelled_out_duration() {
+        // Arrange
+        let input = "in 1 hour 30 minutes";
+
+        // Act
+        let resolved = resolve(input, chrono_tz::UTC);
+
+        // Assert
+        assert_eq!(resolved, instant(2024, 6, 27, 13, 30));
+    }
+
+    #[test]
+    fn resolves_tomorrow_against_date_in_source_timezone() {
+        // Arrange
+        // it is already 2024-06-27 21:00 in Tokyo, so tomorrow is the 28th
+        let input = "tomorrow 09:00";
+
+        // Act
+        let resolved = resolve(input, chrono_tz::Asia::Tokyo);
+
+        // Assert
+        assert_eq!(resolved, wall_clock(2024, 6, 28, 9, 0));
+    }
+
+    #[test]
+    fn resolves_today_to_midnight() {
+        // Arrange
+        // 12:00 UTC is still 2024-06-27 08:00 in New York
+        let input = "Today";
+
+        // Act
+        let resolved = resolve(input, chrono_tz::America::New_York);
+
+        // Assert
+        assert_eq!(resolved, wall_clock(2024, 6, 27, 0, 0));
+    }
+
+    #[test]
+    fn resolves_next_weekday_after_today() {
+        // Arrange
+        // 2024-06-27 is a Thursday; the next Monday is 2024-07-01
+        let input = "next monday 14:30";
+
+        // Act
+        let resolved = resolve(input, chrono_tz::UTC);
+
+        // Assert
+        assert_eq!(resolved, wall_clock(2024, 7, 1, 14, 30));
+    }
+
+    #[test]
+    fn resolves_next_same_weekday_to_a_week_later() {
+        // Arrange
+        let input = "next thursday";
+
+        // Act
+        let resolved = resolve(input, chrono_tz::UTC);
+
+        // Assert
+        assert_eq!(resolved, wall_clock(2024, 7, 4, 0, 0));
+    }
+
+    #[test]
+    fn rejects_unknown_unit() {
+        // Arrange
+        let input = "now + 3 fortnights";
+
+        // Act
+        let result: Result<RelativeTime, RelativeTimeParseError> = input.parse();
+
+        // Assert
+        assert_eq!(result, Err(RelativeTimeParseError(input.to_string())));
+    }
+
+    #[test]
+    fn rejects_shift_without_duration() {
+        // Arrange
+        let input = "in";
+
+        // Act
+        let result: Result<RelativeTime, RelativeTimeParseError> = input.parse();
+
+        // Assert
+        assert_eq!(result, Err(RelativeTimeParseError(input.to_string())));
+    }
+
+    #[test]
+    fn recognizes_expression_opened_by_keyword() {
+        // Arrange
+        let input = "Tomorrow 9am";
+
+        // Act
+        let attempted = RelativeTime::looks_relative(input);
+
+        // Assert
+        assert!(attempted);
+    }
+
+    #[test]
+    fn does_not_recognize_absolute_time() {
+        // Arrange
+        let input = "2024-06-27 12:00:00";
+
+        // Act
+        let attempted = RelativeTime::looks_relative(input);
+
+        // Assert
+        assert!(!attempted);
+    }
+}
@@ -0,0 +1,24 @@
+use crate::domain::conversion_time::ConversionTime;
+use crate::domain::epoch_time::EpochTime;
+
+/// The time a `TranslationRequest` asks about.
+///
+/// A wall-clock time still needs the source timezone to become an
+/// instant; an epoch time already is one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestedTime {
+    WallClock(ConversionTime),
+    Epoch(EpochTime),
+}
+
+impl From<ConversionTime> for RequestedTime {
+    fn from(time: ConversionTime) -> Self {
+        RequestedTime::WallClock(time)
+    }
+}
+
+impl From<EpochTime> for RequestedTime {
+    fn from(time: EpochTime) -> Self {
+        RequestedTime::Epoch(time)
+    }
+}
@@ -1,26 +1,33 @@
 use crate::domain::timezone_parse_error::TimezoneParseError;
-use chrono_tz::Tz;
+use crate::domain::zone::Zone;
+use crate::domain::zone_parse_options::ZoneParseOptions;
 use std::str::FromStr;
 
 /// The timezone the input time is expressed in.
 ///
+/// Accepts an IANA name (`America/New_York`) or a timezone
+/// abbreviation (`EST`); see [`ZoneParseOptions`] for how an ambiguous
+/// abbreviation is settled.
+///
 /// Distinct from [`crate::TargetTimezone`] so that source and target
 /// cannot be swapped by mistake at compile time.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct SourceTimezone(Tz);
+pub struct SourceTimezone(Zone);
 
 impl FromStr for SourceTimezone {
     type Err = TimezoneParseError;
 
     fn from_str(text: &str) -> Result<Self, Self::Err> {
-        text.parse::<Tz>()
-            .map(SourceTimezone)
-            .map_err(|_| TimezoneParseError(text.to_string()))
+        SourceTimezone::parse_with(text, &ZoneParseOptions::default())
     }
 }
 
 impl SourceTimezone {
-    pub fn timezone(&self) -> Tz {
+    pub fn parse_with(text: &str, options: &ZoneParseOptions) -> Result<Self, TimezoneParseError> {
+        options.parse_zone(text).map(SourceTimezone)
+    }
+
+    pub fn timezone(&self) -> Zone {
         self.0
     }
 }
@@ -28,6 +35,7 @@ impl SourceTimezone {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::{NaiveDate, Offset, TimeZone};
 
     #[test]
     fn parses_iana_timezone_name() {
@@ -38,7 +46,7 @@ mod tests {
         let source: SourceTimezone = input.parse().unwrap();
 
         // Assert
-        assert_eq!(source.timezone(), chrono_tz::America::New_York);
+        assert_eq!(source.timezone(), Zone::Iana(chrono_tz::America::New_York));
     }
 
     #[test]
@@ -50,6 +58,134 @@ mod tests {
         let result: Result<SourceTimezone, TimezoneParseError> = input.parse();
 
         // Assert
-        assert_eq!(result, Err(TimezoneParseError(input.to_string())));
+        assert_eq!(result, Err(TimezoneParseError::Unknown(input.to_string())));
+    }
+
+    #[test]
+    fn parses_timezone_abbreviation_as_fixed_offset() {
+        // Arrange
+        let input = "JST";
+
+        // Act
+        let source: SourceTimezone = input.parse().unwrap();
+
+        // Assert
+        assert_eq!(source.timezone().to_string(), "JST");
+    }
+
+    #[test]
+    fn parses_legacy_zone_name_as_abbreviation() {
+        // Arrange
+        // the tz data's `CET` zone would move to CEST in July
+        let input = "CET";
+        let july = NaiveDate::from_ymd_opt(2024, 7, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+
+        // Act
+        let source: SourceTimezone = input.parse().unwrap();
+
+        // Assert
+        let offset = source.timezone().offset_from_utc_datetime(&july);
+        assert_eq!(offset.fix().local_minus_utc(), 3600);
+        assert_eq!(offset.to_string(), "CET");
+    }
+
+    #[test]
+    fn rejects_ambiguous_abbreviation() {
+        // Arrange
+        let input = "IST";
+
+        // Act
+        let result: Result<SourceTimezone, TimezoneParseError> = input.parse();
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(TimezoneParseError::AmbiguousAbbreviation { .. })
+        ));
+    }
+
+    #[test]
+    fn parses_utc_offset_as_fixed_offset() {
+        // Arrange
+        let input = "UTC-3";
+
+        // Act
+        let source: SourceTimezone = input.parse().unwrap();
+
+        // Assert
+        assert_eq!(source.timezone().to_string(), "-03:00");
+    }
+
+    #[test]
+    fn parses_iana_timezone_name_regardless_of_case() {
+        // Arrange
+        let input = "asia/tokyo";
+
+        // Act
+        let source: SourceTimezone = input.parse().unwrap();
+
+        // Assert
+        assert_eq!(source.timezone(), Zone::Iana(chrono_tz::Asia::Tokyo));
+    }
+
+    #[test]
+    fn suggests_close_name_for_misspelled_timezone() {
+        // Arrange
+        let input = "Europe/Londn";
+
+        // Act
+        let result: Result<SourceTimezone, TimezoneParseError> = input.parse();
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(TimezoneParseError::Misspelled {
+                input: input.to_string(),
+                suggestions: vec!["Europe/London"],
+            })
+        );
+    }
+
+    #[test]
+    fn requires_exact_name_in_strict_mode() {
+        // Arrange
+        let input = "asia/tokyo";
+        let options = ZoneParseOptions::default().with_strict(true);
+
+        // Act
+        let result = SourceTimezone::parse_with(input, &options);
+
+        // Assert
+        assert_eq!(result, Err(TimezoneParseError::Unknown(input.to_string())));
+    }
+
+    #[test]
+    fn parses_city_name_as_its_zone() {
+        // Arrange
+        let input = "Bangalore";
+
+        // Act
+        let source: SourceTimezone = input.parse().unwrap();
+
+        // Assert
+        assert_eq!(source.timezone(), Zone::Iana(chrono_tz::Asia::Kolkata));
+    }
+
+    #[test]
+    fn rejects_country_spanning_several_zones() {
+        // Arrange
+        let input = "US";
+
+        // Act
+        let result: Result<SourceTimezone, TimezoneParseError> = input.parse();
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(TimezoneParseError::AmbiguousCountry { .. })
+        ));
     }
 }
@@ -1,26 +1,30 @@
 use crate::domain::timezone_parse_error::TimezoneParseError;
-use chrono_tz::Tz;
+use crate::domain::zone::Zone;
+use crate::domain::zone_parse_options::ZoneParseOptions;
 use std::str::FromStr;
 
 /// The timezone the time should be translated into.
 ///
 /// Distinct from [`crate::SourceTimezone`] so that source and target
-/// cannot be swapped by mistake at compile time.
+/// cannot be swapped by mistake at compile time. Parsed the same way
+/// as the source: an IANA name or a timezone abbreviation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct TargetTimezone(Tz);
+pub struct TargetTimezone(Zone);
 
 impl FromStr for TargetTimezone {
     type Err = TimezoneParseError;
 
     fn from_str(text: &str) -> Result<Self, Self::Err> {
-        text.parse::<Tz>()
-            .map(TargetTimezone)
-            .map_err(|_| TimezoneParseError(text.to_string()))
+        TargetTimezone::parse_with(text, &ZoneParseOptions::default())
     }
 }
 
 impl TargetTimezone {
-    pub fn timezone(&self) -> Tz {
+    pub fn parse_with(text: &str, options: &ZoneParseOptions) -> Result<Self, TimezoneParseError> {
+        options.parse_zone(text).map(TargetTimezone)
+    }
+
+    pub fn timezone(&self) -> Zone {
         self.0
     }
 }
@@ -38,7 +42,7 @@ mod tests {
         let target: TargetTimezone = input.parse().unwrap();
 
         // Assert
-        assert_eq!(target.timezone(), chrono_tz::Asia::Tokyo);
+        assert_eq!(target.timezone(), Zone::Iana(chrono_tz::Asia::Tokyo));
     }
 
     #[test]
@@ -50,6 +54,45 @@ mod tests {
         let result: Result<TargetTimezone, TimezoneParseError> = input.parse();
 
         // Assert
-        assert_eq!(result, Err(TimezoneParseError(input.to_string())));
+        assert_eq!(result, Err(TimezoneParseError::Unknown(input.to_string())));
+    }
+
+    #[test]
+    fn parses_timezone_abbreviation_as_fixed_offset() {
+        // Arrange
+        let input = "JST";
+
+        // Act
+        let target: TargetTimezone = input.parse().unwrap();
+
+        // Assert
+        assert_eq!(target.timezone().to_string(), "JST");
+    }
+
+    #[test]
+    fn rejects_ambiguous_abbreviation() {
+        // Arrange
+        let input = "IST";
+
+        // Act
+        let result: Result<TargetTimezone, TimezoneParseError> = input.parse();
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(TimezoneParseError::AmbiguousAbbreviation { .. })
+        ));
+    }
+
+    #[test]
+    fn parses_utc_offset_as_fixed_offset() {
+        // Arrange
+        let input = "+05:30";
+
+        // Act
+        let target: TargetTimezone = input.parse().unwrap();
+
+        // Assert
+        assert_eq!(target.timezone().to_string(), "+05:30");
     }
 }
@@ -0,0 +1,226 @@
+use crate::domain::preferred_region::{PreferredRegion, REGIONS};
+use crate::domain::zone::NamedOffset;
+use chrono::{FixedOffset, NaiveDate, Offset, TimeZone};
+use chrono_tz::{OffsetName, Tz, TZ_VARIANTS};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::OnceLock;
+
+/// One offset an abbreviation stands for, with the zones that use it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbbreviationCandidate {
+    pub offset: FixedOffset,
+    pub zones: Vec<Tz>,
+}
+
+/// How many zone names a candidate shows before eliding the rest.
+const LISTED_ZONES: usize = 3;
+
+/// Prints `+05:30 (Asia/Calcutta, Asia/Kolkata)`.
+impl fmt::Display for AbbreviationCandidate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<&str> = self
+            .zones
+            .iter()
+            .take(LISTED_ZONES)
+            .map(|zone| zone.name())
+            .collect();
+        let elided = if self.zones.len() > LISTED_ZONES {
+            ", ..."
+        } else {
+            ""
+        };
+        write!(f, "{} ({}{})", self.offset, names.join(", "), elided)
+    }
+}
+
+/// The result of looking up a timezone abbreviation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbbreviationLookup {
+    /// Every candidate agrees on one offset.
+    Resolved(NamedOffset),
+    /// The abbreviation stands for several offsets.
+    Ambiguous(Vec<AbbreviationCandidate>),
+    /// No zone uses the abbreviation.
+    Unknown,
+}
+
+/// Looks up an abbreviation such as `JST` or `cest` (case-insensitive).
+///
+/// When several offsets match, the preferred region (if any) keeps
+/// only the candidates used by at least one zone in that region.
+pub fn lookup_abbreviation(
+    text: &str,
+    preferred_region: Option<PreferredRegion>,
+) -> AbbreviationLookup {
+    let Some((name, candidates)) = abbreviation_index().get_key_value(&text.to_uppercase()) else {
+        return AbbreviationLookup::Unknown;
+    };
+
+    let candidates: Vec<&AbbreviationCandidate> = match (candidates.len(), preferred_region) {
+        (1, _) | (_, None) => candidates.iter().collect(),
+        (_, Some(region)) => {
+            let in_region: Vec<&AbbreviationCandidate> = candidates
+                .iter()
+                .filter(|candidate| {
+                    candidate
+                        .zones
+                        .iter()
+                        .any(|zone| region.contains(zone.name()))
+                })
+                .collect();
+            if in_region.is_empty() {
+                candidates.iter().collect()
+            } else {
+                in_region
+            }
+        }
+    };
+
+    match candidates.as_slice() {
+        [only] => AbbreviationLookup::Resolved(NamedOffset::new(only.offset, Some(name))),
+        _ => AbbreviationLookup::Ambiguous(candidates.into_iter().cloned().collect()),
+    }
+}
+
+/// Abbreviation -> candidates, built once from the chrono-tz data.
+///
+/// Every zone under an IANA region is sampled twice a month over two
+/// reference years, which catches both standard and daylight names.
+/// A fixed window keeps the index deterministic; abbreviations dropped
+/// from use long ago (`LMT`, old wartime names) never enter it.
+/// Candidates are sorted by how many zones use them, most first.
+fn abbreviation_index() -> &'static BTreeMap<String, Vec<AbbreviationCandidate>> {
+    static INDEX: OnceLock<BTreeMap<String, Vec<AbbreviationCandidate>>> = OnceLock::new();
+    INDEX.get_or_init(|| {
+        let mut index: BTreeMap<String, Vec<AbbreviationCandidate>> = BTreeMap::new();
+
+        for zone in TZ_VARIANTS.iter().filter(|zone| is_regional(zone.name())) {
+            for sample in reference_samples() {
+                let offset = zone.offset_from_utc_datetime(&sample);
+                let Some(name) = offset.abbreviation() else {
+                    continue;
+                };
+                let candidates = index.entry(name.to_string()).or_default();
+                let fixed = offset.fix();
+                match candidates.iter_mut().find(|c| c.offset == fixed) {
+                    Some(candidate) if candidate.zones.contains(zone) => {}
+                    Some(candidate) => candidate.zones.push(*zone),
+                    None => candidates.push(AbbreviationCandidate {
+                        offset: fixed,
+                        zones: vec![*zone],
+                    }),
+                }
+            }
+        }
+
+        for candidates in index.values_mut() {
+            candidates.sort_by_key(|candidate| std::cmp::Reverse(candidate.zones.len()));
+        }
+        index
+    })
+}
+
+fn is_regional(zone_name: &str) -> bool {
+    zone_name
+        .split_once('/')
+        .is_some_and(|(area, _)| REGIONS.contains(&area))
+}
+
+fn reference_samples() -> impl Iterator<Item = chrono::NaiveDateTime> {
+    (2024..=2025).flat_map(|year| {
+        (1..=12).flat_map(move |month| {
+            [1, 15].into_iter().map(move |day| {
+                NaiveDate::from_ymd_opt(year, month, day)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+            })
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hours(hours: i32, minutes: i32) -> FixedOffset {
+        FixedOffset::east_opt(hours * 3600 + minutes * 60).unwrap()
+    }
+
+    #[test]
+    fn resolves_abbreviation_used_at_one_offset() {
+        // Arrange
+        let input = "JST";
+
+        // Act
+        let lookup = lookup_abbreviation(input, None);
+
+        // Assert
+        assert_eq!(
+            lookup,
+            AbbreviationLookup::Resolved(NamedOffset::new(hours(9, 0), Some("JST")))
+        );
+    }
+
+    #[test]
+    fn resolves_daylight_abbreviation_case_insensitively() {
+        // Arrange
+        let input = "cest";
+
+        // Act
+        let lookup = lookup_abbreviation(input, None);
+
+        // Assert
+        assert_eq!(
+            lookup,
+            AbbreviationLookup::Resolved(NamedOffset::new(hours(2, 0), Some("CEST")))
+        );
+    }
+
+    #[test]
+    fn lists_every_offset_of_ambiguous_abbreviation() {
+        // Arrange
+        // IST is India, Irish and Israel Standard Time
+        let input = "IST";
+
+        // Act
+        let lookup = lookup_abbreviation(input, None);
+
+        // Assert
+        let AbbreviationLookup::Ambiguous(candidates) = lookup else {
+            panic!("IST must be ambiguous, got {:?}", lookup);
+        };
+        let mut offsets: Vec<FixedOffset> = candidates.iter().map(|c| c.offset).collect();
+        offsets.sort_by_key(|offset| offset.local_minus_utc());
+        assert_eq!(offsets, vec![hours(1, 0), hours(2, 0), hours(5, 30)]);
+    }
+
+    #[test]
+    fn narrows_ambiguous_abbreviation_to_preferred_region() {
+        // Arrange
+        let input = "IST";
+        let region: PreferredRegion = "Europe".parse().unwrap();
+
+        // Act
+        let lookup = lookup_abbreviation(input, Some(region));
+
+        // Assert
+        assert_eq!(
+            lookup,
+            AbbreviationLookup::Resolved(NamedOffset::new(hours(1, 0), Some("IST")))
+        );
+    }
+
+    #[test]
+    fn reports_unknown_abbreviation() {
+        // Arrange
+        let input = "XYZT";
+
+        // Act
+        let lookup = lookup_abbreviation(input, None);
+
+        // Assert
+        assert_eq!(lookup, AbbreviationLookup::Unknown);
+    }
+}
@@ -0,0 +1,149 @@
+use chrono_tz::{Tz, TZ_VARIANTS};
+
+/// How many names a "did you mean" hint offers at most.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// The largest edit distance still worth suggesting, for long inputs.
+/// Shorter inputs allow one edit per four characters (at least one).
+const MAX_SUGGESTION_DISTANCE: usize = 3;
+
+/// Finds the IANA zone whose name equals `text` ignoring ASCII case
+/// (`asia/tokyo` -> `Asia/Tokyo`).
+pub(crate) fn find_ignoring_case(text: &str) -> Option<Tz> {
+    TZ_VARIANTS
+        .iter()
+        .find(|tz| tz.name().eq_ignore_ascii_case(text))
+        .copied()
+}
+
+/// IANA names close to `text`, closest first (`Europe/Londn` ->
+/// `Europe/London`).
+///
+/// Distance is the Levenshtein distance between lowercased names, so a
+/// case slip costs nothing on top of a typo. Input without a `/` is
+/// also compared with the city part alone (`Tokio` -> `Asia/Tokyo`).
+/// Ties keep chrono-tz order.
+pub(crate) fn suggest_timezones(text: &str) -> Vec<&'static str> {
+    let text = text.to_lowercase();
+    let max_distance = (text.chars().count() / 4).clamp(1, MAX_SUGGESTION_DISTANCE);
+    let mut scored: Vec<(usize, &'static str)> = TZ_VARIANTS
+        .iter()
+        .map(|tz| (distance_to(&text, tz.name()), tz.name()))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+    scored.sort_by_key(|(distance, _)| *distance);
+
+    let Some(&(closest, _)) = scored.first() else {
+        return Vec::new();
+    };
+    scored
+        .into_iter()
+        .take_while(|(distance, _)| *distance == closest)
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, name)| name)
+        .collect()
+}
+
+fn distance_to(text: &str, name: &str) -> usize {
+    let name = name.to_lowercase();
+    let full = edit_distance(text, &name);
+    match name.rsplit_once('/') {
+        Some((_, city)) if !text.contains('/') => full.min(edit_distance(text, city)),
+        _ => full,
+    }
+}
+
+/// Levenshtein distance over chars, with a single rolling row.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a_char != *b_char);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_zone_ignoring_case() {
+        // Arrange
+        let input = "asia/tokyo";
+
+        // Act
+        let found = find_ignoring_case(input);
+
+        // Assert
+        assert_eq!(found, Some(chrono_tz::Asia::Tokyo));
+    }
+
+    #[test]
+    fn suggests_name_with_missing_letter() {
+        // Arrange
+        let input = "Europe/Londn";
+
+        // Act
+        let suggestions = suggest_timezones(input);
+
+        // Assert
+        assert_eq!(suggestions, vec!["Europe/London"]);
+    }
+
+    #[test]
+    fn suggests_name_with_missing_underscore() {
+        // Arrange
+        let input = "America/NewYork";
+
+        // Act
+        let suggestions = suggest_timezones(input);
+
+        // Assert
+        assert_eq!(suggestions, vec!["America/New_York"]);
+    }
+
+    #[test]
+    fn suggests_zone_by_city_alone() {
+        // Arrange
+        let input = "Tokio";
+
+        // Act
+        let suggestions = suggest_timezones(input);
+
+        // Assert
+        assert_eq!(suggestions, vec!["Asia/Tokyo"]);
+    }
+
+    #[test]
+    fn suggests_nothing_for_unrelated_text() {
+        // Arrange
+        let input = "Invalid/Timezone";
+
+        // Act
+        let suggestions = suggest_timezones(input);
+
+        // Assert
+        assert!(suggestions.is_empty());
+    }
+
+    #[test]
+    fn measures_edit_distance() {
+        // Arrange
+        let (a, b) = ("kitten", "sitting");
+
+        // Act
+        let distance = edit_distance(a, b);
+
+        // Assert
+        assert_eq!(distance, 3);
+    }
+}
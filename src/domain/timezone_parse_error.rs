@@ -1,9 +1,41 @@
-/// Raised when an input string is not an IANA timezone name.
+use crate::domain::timezone_abbreviation::AbbreviationCandidate;
+use chrono_tz::Tz;
+
+/// Raised when an input string names no timezone, or names several.
 ///
 /// Shared by `SourceTimezone` and `TargetTimezone`: the failure is the
 /// same (unknown timezone name), only the role of the value differs.
 #[derive(thiserror::Error, Debug, PartialEq, Eq)]
-#[error(
-    "Validation Error: Invalid timezone found {0}. @see https://docs.rs/chrono-tz/latest/chrono_tz/enum.Tz.html"
-)]
-pub struct TimezoneParseError(pub String);
+pub enum TimezoneParseError {
+    #[error(
+        "Validation Error: Invalid timezone found {0}. Run `tzt list` to browse valid names. @see https://docs.rs/chrono-tz/latest/chrono_tz/enum.Tz.html"
+    )]
+    Unknown(String),
+
+    /// The input names no timezone but is close to some IANA names.
+    #[error(
+        "Validation Error: Invalid timezone found {input}. did you mean {}?",
+        suggestions.join(" or ")
+    )]
+    Misspelled {
+        input: String,
+        suggestions: Vec<&'static str>,
+    },
+
+    /// The input is an abbreviation used at several offsets (`IST`).
+    #[error(
+        "Validation Error: Ambiguous timezone abbreviation found. {abbreviation} could be {}. Use --prefer-region or an IANA name.",
+        candidates.iter().map(|candidate| candidate.to_string()).collect::<Vec<_>>().join(", ")
+    )]
+    AmbiguousAbbreviation {
+        abbreviation: String,
+        candidates: Vec<AbbreviationCandidate>,
+    },
+
+    /// The input is a country that spans several zones (`US`).
+    #[error(
+        "Validation Error: Ambiguous country found. {country} spans several timezones: {}. Use one of them instead.",
+        zones.iter().map(|zone| zone.name()).collect::<Vec<_>>().join(", ")
+    )]
+    AmbiguousCountry { country: String, zones: Vec<Tz> },
+}
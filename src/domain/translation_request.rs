@@ -1,25 +1,30 @@
 use crate::domain::ambiguous_time_strategy::AmbiguousTimeStrategy;
-use crate::domain::conversion_time::ConversionTime;
+use crate::domain::gap_time_strategy::GapTimeStrategy;
+use crate::domain::requested_time::RequestedTime;
 use crate::domain::source_timezone::SourceTimezone;
 use crate::domain::target_timezone::TargetTimezone;
-use chrono::NaiveDateTime;
-use chrono_tz::Tz;
+use crate::domain::zone::Zone;
 
 /// A complete, validated request to translate a time between timezones.
 ///
 /// Every field is a value object, so an instance can only exist
 /// when all inputs were valid.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// There is always at least one target; more can be added, and the
+/// time is then translated into each of them in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TranslationRequest {
-    time: ConversionTime,
+    time: RequestedTime,
     source: SourceTimezone,
-    target: TargetTimezone,
+    targets: Vec<TargetTimezone>,
     strategy: AmbiguousTimeStrategy,
+    gap_strategy: GapTimeStrategy,
+    source_is_explicit: bool,
 }
 
 impl TranslationRequest {
     pub fn new(
-        time: ConversionTime,
+        time: RequestedTime,
         source: SourceTimezone,
         target: TargetTimezone,
         strategy: AmbiguousTimeStrategy,
@@ -27,24 +32,147 @@ impl TranslationRequest {
         Self {
             time,
             source,
-            target,
+            targets: vec![target],
             strategy,
+            gap_strategy: GapTimeStrategy::default(),
+            source_is_explicit: true,
+        }
+    }
+
+    /// Marks whether the source timezone was chosen by the user or
+    /// only filled in as a default.
+    ///
+    /// A defaulted source yields to an offset written in the time itself;
+    /// an explicit one must agree with it.
+    pub fn with_explicit_source(self, source_is_explicit: bool) -> Self {
+        Self {
+            source_is_explicit,
+            ..self
+        }
+    }
+
+    /// Sets how a time inside a DST gap is resolved (by default, it
+    /// is an error).
+    pub fn with_gap_strategy(self, gap_strategy: GapTimeStrategy) -> Self {
+        Self {
+            gap_strategy,
+            ..self
         }
     }
 
-    pub fn naive_datetime(&self) -> NaiveDateTime {
-        self.time.naive_datetime()
+    /// Appends more target timezones after the first one.
+    pub fn with_additional_targets(mut self, targets: Vec<TargetTimezone>) -> Self {
+        self.targets.extend(targets);
+        self
     }
 
-    pub fn source_timezone(&self) -> Tz {
+    pub fn time(&self) -> RequestedTime {
+        self.time
+    }
+
+    pub fn source_timezone(&self) -> Zone {
         self.source.timezone()
     }
 
-    pub fn target_timezone(&self) -> Tz {
-        self.target.timezone()
+    /// The target timezones, in the order they were given.
+    pub fn target_timezones(&self) -> impl Iterator<Item = Zone> + '_ {
+        self.targets.iter().map(|target| target.timezone())
     }
 
     pub fn strategy(&self) -> AmbiguousTimeStrategy {
         self.strategy
     }
+
+    pub fn gap_strategy(&self) -> GapTimeStrategy {
+        self.gap_strategy
+    }
+
+    pub fn source_is_explicit(&self) -> bool {
+        self.source_is_explicit
+    }
+
+    /// Starts building a request from the values it cannot do without.
+    ///
+    /// Everything else starts at the CLI's defaults: the `earliest`
+    /// ambiguity strategy, the `error` gap strategy and an explicit
+    /// source timezone.
+    pub fn builder(
+        time: impl Into<RequestedTime>,
+        source: SourceTimezone,
+        target: TargetTimezone,
+    ) -> TranslationRequestBuilder {
+        TranslationRequestBuilder {
+            request: Self::new(
+                time.into(),
+                source,
+                target,
+                AmbiguousTimeStrategy::default(),
+            ),
+        }
+    }
+}
+
+/// Builds a `TranslationRequest` one option at a time.
+///
+/// ```
+/// use tzt::{AmbiguousTimeStrategy, ConversionTime, TranslationRequest};
+///
+/// let time: ConversionTime = "2024-11-03 01:30:00".parse()?;
+/// let request = TranslationRequest::builder(time, "America/New_York".parse()?, "UTC".parse()?)
+///     .target("Asia/Tokyo".parse()?)
+///     .ambiguous_time_strategy(AmbiguousTimeStrategy::Latest)
+///     .build();
+///
+/// assert_eq!(request.target_timezones().count(), 2);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranslationRequestBuilder {
+    request: TranslationRequest,
+}
+
+impl TranslationRequestBuilder {
+    /// Adds a target timezone after those already given.
+    pub fn target(self, target: TargetTimezone) -> Self {
+        self.targets([target])
+    }
+
+    /// Adds target timezones after those already given, in order.
+    pub fn targets(self, targets: impl IntoIterator<Item = TargetTimezone>) -> Self {
+        Self {
+            request: self
+                .request
+                .with_additional_targets(targets.into_iter().collect()),
+        }
+    }
+
+    /// Sets how a time that occurs twice (DST ends) is resolved.
+    pub fn ambiguous_time_strategy(self, strategy: AmbiguousTimeStrategy) -> Self {
+        Self {
+            request: TranslationRequest {
+                strategy,
+                ..self.request
+            },
+        }
+    }
+
+    /// Sets how a time inside a DST gap (DST starts) is resolved.
+    pub fn gap_strategy(self, gap_strategy: GapTimeStrategy) -> Self {
+        Self {
+            request: self.request.with_gap_strategy(gap_strategy),
+        }
+    }
+
+    /// Marks whether the source timezone was chosen by the user or
+    /// only filled in as a default; see
+    /// `TranslationRequest::with_explicit_source`.
+    pub fn explicit_source(self, source_is_explicit: bool) -> Self {
+        Self {
+            request: self.request.with_explicit_source(source_is_explicit),
+        }
+    }
+
+    pub fn build(self) -> TranslationRequest {
+        self.request
+    }
 }
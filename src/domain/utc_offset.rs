@@ -0,0 +1,180 @@
+use chrono::FixedOffset;
+use std::str::FromStr;
+
+/// The largest offset accepted, as in ISO 8601 / RFC 9557.
+const MAX_OFFSET_HOURS: i32 = 18;
+
+/// Reads a fixed UTC offset written as a timezone: `+05:30`, `-0300`,
+/// `+9`, optionally after `UTC` or `GMT` (`UTC-3`, `GMT+9`).
+///
+/// The sign means what it says: `GMT+9` is nine hours ahead of UTC.
+/// (The POSIX-style IANA names such as `Etc/GMT+9`, where the sign is
+/// inverted, are IANA zones and never reach this function.)
+pub(crate) fn parse_utc_offset(text: &str) -> Option<FixedOffset> {
+    let text = text.trim();
+    let signed = strip_prefix_ignore_case(text, "UTC")
+        .or_else(|| strip_prefix_ignore_case(text, "GMT"))
+        .unwrap_or(text);
+
+    let (sign, digits) = match signed.as_bytes().first()? {
+        b'+' => (1, &signed[1..]),
+        b'-' => (-1, &signed[1..]),
+        _ => return None,
+    };
+    let (hours, minutes) = split_hours_minutes(digits)?;
+    if hours > MAX_OFFSET_HOURS || minutes >= 60 || (hours == MAX_OFFSET_HOURS && minutes > 0) {
+        return None;
+    }
+
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+/// A fixed UTC offset given on its own (`tzt list --offset +09:00`),
+/// in any form `parse_utc_offset` reads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UtcOffset(FixedOffset);
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error("Validation Error: Invalid UTC offset found. {0} (expected e.g. +09:00, -0330, UTC+9)")]
+pub struct UtcOffsetParseError(pub String);
+
+impl FromStr for UtcOffset {
+    type Err = UtcOffsetParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse_utc_offset(text)
+            .map(UtcOffset)
+            .ok_or_else(|| UtcOffsetParseError(text.to_string()))
+    }
+}
+
+impl UtcOffset {
+    pub fn offset(&self) -> FixedOffset {
+        self.0
+    }
+}
+
+fn strip_prefix_ignore_case<'a>(text: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = text.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix)
+        .then(|| &text[prefix.len()..])
+}
+
+/// Splits `5`, `05`, `0530` or `05:30` into hours and minutes.
+fn split_hours_minutes(digits: &str) -> Option<(i32, i32)> {
+    let (hours, minutes) = match digits.split_once(':') {
+        Some((hours, minutes)) if minutes.len() == 2 => (hours, minutes),
+        Some(_) => return None,
+        None if digits.len() == 4 => digits.split_at(2),
+        None => (digits, "0"),
+    };
+    if !(1..=2).contains(&hours.len())
+        || !hours
+            .bytes()
+            .chain(minutes.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    Some((hours.parse().ok()?, minutes.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offset(hours: i32, minutes: i32) -> Option<FixedOffset> {
+        FixedOffset::east_opt(hours * 3600 + minutes * 60)
+    }
+
+    #[test]
+    fn parses_utc_offset_value() {
+        // Arrange
+        let input = "UTC+9";
+
+        // Act
+        let parsed: Result<UtcOffset, UtcOffsetParseError> = input.parse();
+
+        // Assert
+        assert_eq!(
+            parsed.map(|utc_offset| utc_offset.offset()).ok(),
+            offset(9, 0)
+        );
+        assert_eq!(
+            "Asia/Tokyo".parse::<UtcOffset>(),
+            Err(UtcOffsetParseError("Asia/Tokyo".to_string()))
+        );
+    }
+
+    #[test]
+    fn parses_numeric_offset_with_colon() {
+        // Arrange
+        let input = "+05:30";
+
+        // Act
+        let parsed = parse_utc_offset(input);
+
+        // Assert
+        assert_eq!(parsed, offset(5, 30));
+    }
+
+    #[test]
+    fn parses_numeric_offset_without_colon() {
+        // Arrange
+        let input = "-0330";
+
+        // Act
+        let parsed = parse_utc_offset(input);
+
+        // Assert
+        assert_eq!(parsed, offset(-3, -30));
+    }
+
+    #[test]
+    fn parses_hours_after_utc_prefix() {
+        // Arrange
+        let input = "UTC-3";
+
+        // Act
+        let parsed = parse_utc_offset(input);
+
+        // Assert
+        assert_eq!(parsed, offset(-3, 0));
+    }
+
+    #[test]
+    fn reads_gmt_prefix_with_its_literal_sign() {
+        // Arrange
+        let input = "gmt+9";
+
+        // Act
+        let parsed = parse_utc_offset(input);
+
+        // Assert
+        assert_eq!(parsed, offset(9, 0));
+    }
+
+    #[test]
+    fn rejects_offset_beyond_eighteen_hours() {
+        // Arrange
+        let input = "+19:00";
+
+        // Act
+        let parsed = parse_utc_offset(input);
+
+        // Assert
+        assert_eq!(parsed, None);
+    }
+
+    #[test]
+    fn rejects_unsigned_number() {
+        // Arrange
+        let input = "0530";
+
+        // Act
+        let parsed = parse_utc_offset(input);
+
+        // Assert
+        assert_eq!(parsed, None);
+    }
+}
@@ -0,0 +1,276 @@
+use chrono::{FixedOffset, MappedLocalTime, NaiveDate, NaiveDateTime, Offset, TimeDelta, TimeZone};
+use chrono_tz::{OffsetComponents, OffsetName, Tz, TzOffset};
+use std::fmt;
+
+/// A timezone a time can be expressed in.
+///
+/// Most inputs name an IANA zone, whose offset follows DST rules.
+/// A timezone abbreviation (`JST`, `CEST`) instead pins one offset
+/// all year: someone who writes "3pm EST" in July still means UTC-5.
+/// That holds for `CET` too, although the tz data also has a `CET`
+/// zone; only the rule-named zones that no region uses as an
+/// abbreviation (`MET`, `EST5EDT`) are read as IANA zones and follow
+/// DST.
+///
+/// `Zone` implements chrono's `TimeZone`, so `DateTime<Zone>` converts
+/// and prints the same way whichever kind of zone it holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Zone {
+    Iana(Tz),
+    Fixed(NamedOffset),
+}
+
+/// A fixed UTC offset, with the abbreviation it was written as (if any).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NamedOffset {
+    offset: FixedOffset,
+    name: Option<&'static str>,
+}
+
+impl NamedOffset {
+    pub fn new(offset: FixedOffset, name: Option<&'static str>) -> Self {
+        Self { offset, name }
+    }
+}
+
+/// The offset of a `Zone` at some instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZoneOffset {
+    Iana(TzOffset),
+    Fixed(NamedOffset),
+}
+
+impl ZoneOffset {
+    /// The abbreviation in effect (`JST`, `BST`), if the zone has one.
+    /// Zones without a letter abbreviation report `None`.
+    pub fn abbreviation(&self) -> Option<&str> {
+        match self {
+            ZoneOffset::Iana(offset) => offset.abbreviation(),
+            ZoneOffset::Fixed(offset) => offset.name,
+        }
+    }
+
+    /// Whether daylight saving time is in effect. A fixed offset never
+    /// observes DST, even when written as a daylight abbreviation.
+    ///
+    /// Only a shift ahead of the base offset counts. The tz data gives
+    /// Europe/Dublin a base of +01:00 and a negative shift in winter,
+    /// so its winter GMT is not DST; its summer IST, which carries no
+    /// shift, cannot be told from standard time by the offset alone
+    /// (`ZoneInfo` tells it from the zone's next transition).
+    pub fn is_dst(&self) -> bool {
+        self.dst_shift() > TimeDelta::zero()
+    }
+
+    /// The zone's standard (non-DST) UTC offset at this instant: the
+    /// smaller of the offsets with and without the DST shift.
+    pub fn standard_offset(&self) -> FixedOffset {
+        offset_of(self.fix().local_minus_utc() - self.dst_shift().num_seconds().max(0) as i32)
+    }
+
+    /// The zone's daylight saving offset at this instant, when the
+    /// offset in effect carries a DST shift: the larger of the offsets
+    /// with and without it. `None` without a shift.
+    pub fn daylight_offset(&self) -> Option<FixedOffset> {
+        let shift: i32 = self.dst_shift().num_seconds() as i32;
+        (shift != 0).then(|| offset_of(self.fix().local_minus_utc() + (-shift).max(0)))
+    }
+
+    fn dst_shift(&self) -> TimeDelta {
+        match self {
+            ZoneOffset::Iana(offset) => offset.dst_offset(),
+            ZoneOffset::Fixed(_) => TimeDelta::zero(),
+        }
+    }
+}
+
+// tz data offsets and their DST shifts are always within a day
+fn offset_of(seconds: i32) -> FixedOffset {
+    FixedOffset::east_opt(seconds).unwrap()
+}
+
+impl Offset for ZoneOffset {
+    fn fix(&self) -> FixedOffset {
+        match self {
+            ZoneOffset::Iana(offset) => offset.fix(),
+            ZoneOffset::Fixed(offset) => offset.offset,
+        }
+    }
+}
+
+/// Prints the abbreviation (`JST`), or the numeric offset (`+05:30`)
+/// when there is none.
+impl fmt::Display for ZoneOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ZoneOffset::Iana(offset) => fmt::Display::fmt(offset, f),
+            ZoneOffset::Fixed(NamedOffset {
+                name: Some(name), ..
+            }) => f.write_str(name),
+            ZoneOffset::Fixed(NamedOffset { offset, .. }) => fmt::Display::fmt(offset, f),
+        }
+    }
+}
+
+impl fmt::Display for Zone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Zone::Iana(tz) => f.write_str(tz.name()),
+            Zone::Fixed(offset) => fmt::Display::fmt(&ZoneOffset::Fixed(*offset), f),
+        }
+    }
+}
+
+impl TimeZone for Zone {
+    type Offset = ZoneOffset;
+
+    fn from_offset(offset: &ZoneOffset) -> Self {
+        match offset {
+            ZoneOffset::Iana(offset) => Zone::Iana(Tz::from_offset(offset)),
+            ZoneOffset::Fixed(offset) => Zone::Fixed(*offset),
+        }
+    }
+
+    #[allow(deprecated)]
+    fn offset_from_local_date(&self, local: &NaiveDate) -> MappedLocalTime<ZoneOffset> {
+        match self {
+            Zone::Iana(tz) => tz.offset_from_local_date(local).map(ZoneOffset::Iana),
+            Zone::Fixed(offset) => MappedLocalTime::Single(ZoneOffset::Fixed(*offset)),
+        }
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> MappedLocalTime<ZoneOffset> {
+        match self {
+            Zone::Iana(tz) => tz.offset_from_local_datetime(local).map(ZoneOffset::Iana),
+            Zone::Fixed(offset) => MappedLocalTime::Single(ZoneOffset::Fixed(*offset)),
+        }
+    }
+
+    #[allow(deprecated)]
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> ZoneOffset {
+        match self {
+            Zone::Iana(tz) => ZoneOffset::Iana(tz.offset_from_utc_date(utc)),
+            Zone::Fixed(offset) => ZoneOffset::Fixed(*offset),
+        }
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> ZoneOffset {
+        match self {
+            Zone::Iana(tz) => ZoneOffset::Iana(tz.offset_from_utc_datetime(utc)),
+            Zone::Fixed(offset) => ZoneOffset::Fixed(*offset),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn jst() -> Zone {
+        Zone::Fixed(NamedOffset::new(
+            FixedOffset::east_opt(9 * 3600).unwrap(),
+            Some("JST"),
+        ))
+    }
+
+    #[test]
+    fn prints_abbreviation_of_fixed_zone() {
+        // Arrange
+        let instant = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+
+        // Act
+        let output = instant.with_timezone(&jst()).to_string();
+
+        // Assert
+        assert_eq!(output, "2024-01-01 21:00:00 JST");
+    }
+
+    #[test]
+    fn prints_numeric_offset_of_unnamed_fixed_zone() {
+        // Arrange
+        let zone = Zone::Fixed(NamedOffset::new(
+            FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap(),
+            None,
+        ));
+        let instant = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+
+        // Act
+        let output = instant.with_timezone(&zone).to_string();
+
+        // Assert
+        assert_eq!(output, "2024-01-01 17:30:00 +05:30");
+    }
+
+    #[test]
+    fn follows_dst_rules_of_iana_zone() {
+        // Arrange
+        let zone = Zone::Iana(chrono_tz::Europe::London);
+        let instant = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+
+        // Act
+        let output = instant.with_timezone(&zone).to_string();
+
+        // Assert
+        assert_eq!(output, "2024-07-01 13:00:00 BST");
+    }
+
+    #[test]
+    fn reports_dst_and_abbreviation_of_iana_zone() {
+        // Arrange
+        let zone = Zone::Iana(chrono_tz::Europe::London);
+        let instant = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+
+        // Act
+        let offset = *instant.with_timezone(&zone).offset();
+
+        // Assert
+        assert!(offset.is_dst());
+        assert_eq!(offset.abbreviation(), Some("BST"));
+    }
+
+    #[test]
+    fn reads_dublin_winter_as_standard_time() {
+        // Arrange
+        // the tz data gives Dublin a base of +01:00 and a -1h shift in winter
+        let zone = Zone::Iana(chrono_tz::Europe::Dublin);
+        let instant = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+
+        // Act
+        let offset = *instant.with_timezone(&zone).offset();
+
+        // Assert
+        assert!(!offset.is_dst());
+        assert_eq!(offset.standard_offset().local_minus_utc(), 0);
+        assert_eq!(offset.daylight_offset().unwrap().local_minus_utc(), 3600);
+    }
+
+    #[test]
+    fn reads_dublin_summer_without_shift() {
+        // Arrange
+        // IST carries no shift in the tz data, so only `ZoneInfo` sees it as DST
+        let zone = Zone::Iana(chrono_tz::Europe::Dublin);
+        let instant = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+
+        // Act
+        let offset = *instant.with_timezone(&zone).offset();
+
+        // Assert
+        assert!(!offset.is_dst());
+        assert_eq!(offset.abbreviation(), Some("IST"));
+        assert_eq!(offset.daylight_offset(), None);
+    }
+
+    #[test]
+    fn reports_no_dst_for_fixed_zone() {
+        // Arrange
+        let instant = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+
+        // Act
+        let offset = *instant.with_timezone(&jst()).offset();
+
+        // Assert
+        assert!(!offset.is_dst());
+        assert_eq!(offset.abbreviation(), Some("JST"));
+    }
+}
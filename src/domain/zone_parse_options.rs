@@ -0,0 +1,96 @@
+use crate::domain::place_lookup::{lookup_place, PlaceLookup};
+use crate::domain::preferred_region::PreferredRegion;
+use crate::domain::timezone_abbreviation::{lookup_abbreviation, AbbreviationLookup};
+use crate::domain::timezone_name_match::{find_ignoring_case, suggest_timezones};
+use crate::domain::timezone_parse_error::TimezoneParseError;
+use crate::domain::utc_offset::parse_utc_offset;
+use crate::domain::zone::{NamedOffset, Zone};
+use chrono_tz::Tz;
+
+/// Settings that steer how a timezone name is read.
+///
+/// `Default` reads names the way `FromStr` does: IANA names match
+/// regardless of case, and an unknown name comes with suggestions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ZoneParseOptions {
+    preferred_region: Option<PreferredRegion>,
+    strict: bool,
+}
+
+impl ZoneParseOptions {
+    pub fn with_preferred_region(self, preferred_region: Option<PreferredRegion>) -> Self {
+        Self {
+            preferred_region,
+            ..self
+        }
+    }
+
+    /// In strict mode an IANA name must match exactly, and unknown
+    /// names get no "did you mean" suggestions.
+    pub fn with_strict(self, strict: bool) -> Self {
+        Self { strict, ..self }
+    }
+
+    /// Reads `text` as an IANA name first, then as a UTC offset
+    /// (`+05:30`, `UTC-3`), then as an abbreviation, then as a city
+    /// or country.
+    ///
+    /// A name that is also an abbreviation in use (`CET`, `EET`, `WET`)
+    /// is read as the abbreviation, not as the tz data's legacy zone of
+    /// that name, which follows DST.
+    pub(crate) fn parse_zone(&self, text: &str) -> Result<Zone, TimezoneParseError> {
+        if text.chars().all(|c| c.is_ascii_alphabetic()) {
+            if let AbbreviationLookup::Resolved(offset) =
+                lookup_abbreviation(text, self.preferred_region)
+            {
+                return Ok(Zone::Fixed(offset));
+            }
+        }
+        if let Ok(tz) = text.parse::<Tz>() {
+            return Ok(Zone::Iana(tz));
+        }
+        if !self.strict {
+            if let Some(tz) = find_ignoring_case(text) {
+                return Ok(Zone::Iana(tz));
+            }
+        }
+        // before abbreviations: chrono-tz names some zones `+05` and the like
+        if let Some(offset) = parse_utc_offset(text) {
+            return Ok(Zone::Fixed(NamedOffset::new(offset, None)));
+        }
+
+        match lookup_abbreviation(text, self.preferred_region) {
+            AbbreviationLookup::Resolved(offset) => Ok(Zone::Fixed(offset)),
+            AbbreviationLookup::Ambiguous(candidates) => {
+                Err(TimezoneParseError::AmbiguousAbbreviation {
+                    abbreviation: text.to_string(),
+                    candidates,
+                })
+            }
+            AbbreviationLookup::Unknown => match lookup_place(text) {
+                PlaceLookup::Resolved(tz) => Ok(Zone::Iana(tz)),
+                PlaceLookup::SeveralZones(zones) => Err(TimezoneParseError::AmbiguousCountry {
+                    country: text.to_string(),
+                    zones,
+                }),
+                PlaceLookup::Unknown => Err(self.unknown(text)),
+            },
+        }
+    }
+
+    fn unknown(&self, text: &str) -> TimezoneParseError {
+        let suggestions = if self.strict {
+            Vec::new()
+        } else {
+            suggest_timezones(text)
+        };
+        if suggestions.is_empty() {
+            TimezoneParseError::Unknown(text.to_string())
+        } else {
+            TimezoneParseError::Misspelled {
+                input: text.to_string(),
+                suggestions,
+            }
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use crate::domain::zone::{Zone, ZoneOffset};
+use chrono::{DateTime, SubsecRound, TimeDelta, TimeZone, Utc};
+
+/// How far from the starting instant transitions are looked for: a
+/// decade. A zone whose offset has held that long (Asia/Tokyo, since
+/// 1951) reports no transition, rather than being walked back a day
+/// at a time to its last one.
+const SEARCH_DAYS: i64 = 10 * 366;
+
+/// A change of a zone's offset or abbreviation: clocks jump forward or
+/// back, or the zone moves to another standard time.
+///
+/// The zone's transitions are not listed by the tz data it is built
+/// from, so they are searched for: a day at a time, then to the exact
+/// second. The daily samples only see a change whose offset is still
+/// in effect at the next one, so two transitions within the same day
+/// (a zone that moves its clocks and moves them back before the day
+/// is out) are missed, and read as none.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZoneTransition {
+    zone: Zone,
+    instant: DateTime<Utc>,
+}
+
+impl ZoneTransition {
+    /// The first transition strictly after `instant`, or `None` when
+    /// the zone keeps its offset for the next decade. A fixed offset
+    /// has no transitions.
+    pub fn next_after(zone: Zone, instant: DateTime<Utc>) -> Option<ZoneTransition> {
+        let Zone::Iana(_) = zone else {
+            return None;
+        };
+        let mut earlier: DateTime<Utc> = instant.trunc_subsecs(0);
+        for _ in 0..SEARCH_DAYS {
+            let later = earlier + TimeDelta::days(1);
+            if offset_at(zone, later) != offset_at(zone, earlier) {
+                return Some(Self::narrow(zone, earlier, later));
+            }
+            earlier = later;
+        }
+        None
+    }
+
+    /// The last transition at or before `instant`, which put the
+    /// zone's current offset in effect, or `None` when there was none
+    /// in the last decade. A fixed offset has no transitions.
+    pub fn previous_before(zone: Zone, instant: DateTime<Utc>) -> Option<ZoneTransition> {
+        let Zone::Iana(_) = zone else {
+            return None;
+        };
+        let mut later: DateTime<Utc> = instant.trunc_subsecs(0);
+        for _ in 0..SEARCH_DAYS {
+            let earlier = later - TimeDelta::days(1);
+            if offset_at(zone, earlier) != offset_at(zone, later) {
+                return Some(Self::narrow(zone, earlier, later));
+            }
+            later = earlier;
+        }
+        None
+    }
+
+    /// Bisects `earlier..later`, whose ends have different offsets, to
+    /// the first second of the new offset.
+    fn narrow(zone: Zone, mut earlier: DateTime<Utc>, mut later: DateTime<Utc>) -> ZoneTransition {
+        let offset_before: ZoneOffset = offset_at(zone, earlier);
+        while later - earlier > TimeDelta::seconds(1) {
+            let middle = earlier + TimeDelta::seconds((later - earlier).num_seconds() / 2);
+            if offset_at(zone, middle) == offset_before {
+                earlier = middle;
+            } else {
+                later = middle;
+            }
+        }
+        ZoneTransition {
+            zone,
+            instant: later,
+        }
+    }
+
+    /// The instant the new offset takes effect.
+    pub fn instant(&self) -> DateTime<Utc> {
+        self.instant
+    }
+
+    /// The last whole second before the transition (01:59:59 BST).
+    pub fn before(&self) -> DateTime<Zone> {
+        (self.instant - TimeDelta::seconds(1)).with_timezone(&self.zone)
+    }
+
+    /// The transition instant, read with the new offset (01:00:00 GMT).
+    pub fn after(&self) -> DateTime<Zone> {
+        self.instant.with_timezone(&self.zone)
+    }
+}
+
+fn offset_at(zone: Zone, instant: DateTime<Utc>) -> ZoneOffset {
+    zone.offset_from_utc_datetime(&instant.naive_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::zone::NamedOffset;
+    use chrono::{FixedOffset, Offset};
+
+    fn london() -> Zone {
+        Zone::Iana(chrono_tz::Europe::London)
+    }
+
+    #[test]
+    fn finds_next_fall_back_to_the_second() {
+        // Arrange
+        // London leaves BST at 01:00 UTC on 2024-10-27
+        let now = Utc.with_ymd_and_hms(2024, 6, 27, 12, 0, 0).unwrap();
+
+        // Act
+        let transition = ZoneTransition::next_after(london(), now).unwrap();
+
+        // Assert
+        assert_eq!(
+            transition.instant(),
+            Utc.with_ymd_and_hms(2024, 10, 27, 1, 0, 0).unwrap()
+        );
+        assert_eq!(transition.before().to_string(), "2024-10-27 01:59:59 BST");
+        assert_eq!(transition.after().to_string(), "2024-10-27 01:00:00 GMT");
+    }
+
+    #[test]
+    fn finds_previous_spring_forward() {
+        // Arrange
+        let now = Utc.with_ymd_and_hms(2024, 6, 27, 12, 0, 0).unwrap();
+
+        // Act
+        let transition = ZoneTransition::previous_before(london(), now).unwrap();
+
+        // Assert
+        assert_eq!(
+            transition.instant(),
+            Utc.with_ymd_and_hms(2024, 3, 31, 1, 0, 0).unwrap()
+        );
+        assert_eq!(transition.before().offset().fix().local_minus_utc(), 0);
+        assert_eq!(transition.after().offset().fix().local_minus_utc(), 3600);
+    }
+
+    #[test]
+    fn finds_last_change_within_a_decade() {
+        // Arrange
+        // Turkey stayed on summer time for good in September 2016
+        let now = Utc.with_ymd_and_hms(2024, 6, 27, 12, 0, 0).unwrap();
+        let istanbul = Zone::Iana(chrono_tz::Europe::Istanbul);
+
+        // Act
+        let previous = ZoneTransition::previous_before(istanbul, now).unwrap();
+
+        // Assert
+        assert_eq!(previous.instant().format("%Y-%m").to_string(), "2016-09");
+    }
+
+    #[test]
+    fn finds_nothing_for_zone_steady_for_a_decade() {
+        // Arrange
+        // Japan last left daylight saving time in September 1951
+        let now = Utc.with_ymd_and_hms(2024, 6, 27, 12, 0, 0).unwrap();
+        let tokyo = Zone::Iana(chrono_tz::Asia::Tokyo);
+
+        // Act
+        let previous = ZoneTransition::previous_before(tokyo, now);
+        let next = ZoneTransition::next_after(tokyo, now);
+
+        // Assert
+        assert_eq!((previous, next), (None, None));
+    }
+
+    #[test]
+    fn finds_nothing_for_fixed_offset() {
+        // Arrange
+        let zone = Zone::Fixed(NamedOffset::new(
+            FixedOffset::east_opt(9 * 3600).unwrap(),
+            Some("JST"),
+        ));
+        let now = Utc.with_ymd_and_hms(2024, 6, 27, 12, 0, 0).unwrap();
+
+        // Act
+        let previous = ZoneTransition::previous_before(zone, now);
+        let next = ZoneTransition::next_after(zone, now);
+
+        // Assert
+        assert_eq!((previous, next), (None, None));
+    }
+}
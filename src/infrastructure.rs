@@ -1,4 +1,6 @@
 pub mod local_timezone_string_provider;
+pub mod system_clock;
 mod system_timezone_sources;
 
 pub use self::local_timezone_string_provider::provide_local_timezone_string;
+pub use self::system_clock::SystemClock;
@@ -0,0 +1,11 @@
+use crate::domain::Clock;
+use chrono::{DateTime, Utc};
+
+/// Reads the current instant from the system clock.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
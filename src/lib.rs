@@ -0,0 +1,62 @@
+//! Translate a time from one timezone to another.
+//!
+//! This is the library behind the `tzt` command. It exposes the
+//! domain and usecase layers: parse each input into its value object,
+//! assemble a `TranslationRequest`, and hand it to a
+//! `TimezoneTranslator`.
+//!
+//! ```
+//! use tzt::{ConversionTime, TimezoneTranslator, TranslationRequest};
+//!
+//! let time: ConversionTime = "2024-01-01 12:00:00".parse()?;
+//! let request = TranslationRequest::builder(time, "UTC".parse()?, "Asia/Tokyo".parse()?)
+//!     .target("Europe/London".parse()?)
+//!     .build();
+//!
+//! let translation = TimezoneTranslator::new(request).convert()?;
+//! let formatted: Vec<String> = translation
+//!     .target_times()
+//!     .iter()
+//!     .map(|time| time.format("%Y-%m-%d %H:%M:%S %Z").to_string())
+//!     .collect();
+//!
+//! assert_eq!(
+//!     formatted,
+//!     ["2024-01-01 21:00:00 JST", "2024-01-01 12:00:00 GMT"]
+//! );
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! Every value object parses with `FromStr` and has its own parse
+//! error, whose message is the one the CLI prints. The result of a
+//! translation (`Translation`) keeps each resolved instant
+//! (`Occurrence`) with its image in every target timezone, and records
+//! which strategy stepped in when the time was ambiguous or fell into
+//! a DST gap.
+//!
+//! `TimezoneLister` answers the other question the CLI handles
+//! (`tzt list`): which IANA timezones exist, filtered by name, region
+//! or current UTC offset. `ZoneInspector` describes one of them
+//! (`tzt info`): its current offset and DST state, and the
+//! `ZoneTransition`s before and after now.
+//!
+//! The command-line interface itself (argument parsing, output
+//! formatting, exit codes) lives in the `tzt` binary and is not part
+//! of this API.
+
+pub mod domain;
+pub mod infrastructure;
+pub mod usecase;
+
+pub use self::domain::{
+    AmbiguousTimeStrategy, Clock, ConversionTime, EpochTime, EpochUnit, FixedClock,
+    GapTimeStrategy, ListOrder, ListRequest, RelativeTime, RequestedTime, SourceTimezone,
+    TargetTimezone, TranslationRequest, TranslationRequestBuilder, UtcOffset, Zone, ZoneTransition,
+};
+pub use self::infrastructure::SystemClock;
+pub use self::usecase::timezone_lister::TimezoneLister;
+pub use self::usecase::translation::{Occurrence, Translation};
+pub use self::usecase::translation_error::TranslationError;
+pub use self::usecase::zone_info::ZoneInfo;
+pub use self::usecase::zone_inspector::ZoneInspector;
+pub use self::usecase::TimezoneTranslator;
@@ -1,7 +1,9 @@
-mod domain;
-mod infrastructure;
 mod presentation;
-mod usecase;
+
+// The binary is a consumer of the library crate. The layers are
+// imported under their own names, so `crate::domain` and friends
+// resolve to the library's modules.
+use tzt::{domain, infrastructure, usecase};
 
 use std::process::ExitCode;
 
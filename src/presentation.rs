@@ -1,36 +1,323 @@
+mod batch;
 mod command;
+mod failure;
+mod formatter;
+mod ndjson;
+mod rewrite;
+#[cfg(test)]
+mod stream_fixture;
+mod table;
 mod validator;
 
+use self::batch::Batch;
 use self::command::receiver::receive_user_input;
-use self::validator::command_options_validator::validate_command_options;
-use crate::domain::TranslationRequest;
+use self::failure::Failure;
+use self::formatter::json_formatter::{failure_json, zone_info_json};
+use self::formatter::output_formatter::OutputFormatter;
+use self::formatter::zone_info_formatter::render_zone_info;
+use self::formatter::zone_list_formatter::render_zone_list;
+use self::ndjson::field_path::FieldPath;
+use self::ndjson::Ndjson;
+use self::rewrite::Rewrite;
+use self::table::column_selector::ColumnSelector;
+use self::table::table_error::TableError;
+use self::table::Table;
+use self::validator::command_options_validator::{validate_command_options, RequestOptions};
+use self::validator::info_options_validator::validate_info_options;
+use self::validator::list_options_validator::validate_list_options;
+use self::validator::output_options_validator::validate_output_options;
+use self::validator::stream_options_validator::{validate_stream_options, StreamOutput};
+use crate::domain::{Clock, TranslationRequest};
+use crate::infrastructure::SystemClock;
+use crate::usecase::timezone_lister::TimezoneLister;
+use crate::usecase::translation::Translation;
+use crate::usecase::zone_inspector::ZoneInspector;
 use crate::usecase::TimezoneTranslator;
 use clap::ArgMatches;
+use std::fs::File;
+use std::io::{self, Read, Write};
 use std::process::ExitCode;
 
 /// Entry point of the CLI.
 ///
-/// Receives user input, parses it into a `TranslationRequest`,
-/// translates the time, and prints the result (or an error to stderr).
+/// Receives user input, parses it into a `TranslationRequest` and an
+/// `OutputFormatter`, translates the time, and prints one line per
+/// target timezone, or one JSON object with `--json`. A time moved
+/// out of a DST gap is announced on stderr.
+///
+/// With `--stdin` (or `--time -`), every line of stdin is translated
+/// the same way instead; see `Batch`. With `--csv` or `--tsv`, the
+/// chosen columns of a table are; see `Table`. With `--rewrite`, the
+/// timestamps inside lines of stdin are; see `Rewrite`. With
+/// `--ndjson`, the fields of JSON documents are; see `Ndjson`.
+///
+/// `tzt list` prints the timezones instead; see `run_list`. `tzt info`
+/// describes one of them; see `run_info`.
+///
+/// A failure goes to stderr (as JSON with `--json`) and exits with the
+/// code `Failure` assigns to its kind.
 pub fn run() -> ExitCode {
     let user_input_options: ArgMatches = receive_user_input();
 
-    let request: TranslationRequest = match validate_command_options(&user_input_options) {
-        Ok(request) => request,
-        Err(e) => {
-            eprintln!("{}", e);
-            return ExitCode::FAILURE;
+    if let Some(("list", list_options)) = user_input_options.subcommand() {
+        return run_list(&user_input_options, list_options);
+    }
+    if let Some(("info", info_options)) = user_input_options.subcommand() {
+        return run_info(info_options);
+    }
+    if reads_stdin(&user_input_options) {
+        return run_batch(&user_input_options);
+    }
+    if user_input_options.get_flag("rewrite") {
+        return run_rewrite(&user_input_options);
+    }
+    if user_input_options.get_flag("ndjson") {
+        return run_ndjson(&user_input_options);
+    }
+    if let Some((path, delimiter)) = table_source(&user_input_options) {
+        return run_table(&user_input_options, path, delimiter);
+    }
+
+    match translate(&user_input_options) {
+        Ok((lines, notice)) => {
+            if let Some(notice) = notice {
+                eprintln!("{}", notice);
+            }
+            for line in lines {
+                println!("{}", line);
+            }
+            ExitCode::SUCCESS
         }
+        Err(failure) => report_failure(&user_input_options, &failure),
+    }
+}
+
+/// The lines printed for the user input and the DST-gap notice, if
+/// any, or the first failure.
+fn translate(user_input_options: &ArgMatches) -> Result<(Vec<String>, Option<String>), Failure> {
+    let request: TranslationRequest = validate_command_options(user_input_options, &SystemClock)?;
+    let formatter: OutputFormatter = validate_output_options(user_input_options)?;
+    let translation: Translation = TimezoneTranslator::new(request).convert()?;
+
+    Ok((
+        formatter.render(&translation)?,
+        formatter.notice(&translation),
+    ))
+}
+
+fn reads_stdin(user_input_options: &ArgMatches) -> bool {
+    user_input_options.get_flag("stdin")
+        || user_input_options
+            .get_one::<String>("time")
+            .is_some_and(|time| time == "-")
+}
+
+/// Translates one line, cell or field read in a stream mode, and
+/// renders it: the printed lines, and the DST-gap notice if any.
+fn render_text(
+    options: &RequestOptions,
+    formatter: &OutputFormatter,
+    time_text: &str,
+    clock: &impl Clock,
+) -> Result<(Vec<String>, Option<String>), Failure> {
+    let request: TranslationRequest = options.request_for(time_text, clock)?;
+    let translation: Translation = TimezoneTranslator::new(request).convert()?;
+    Ok((
+        formatter.render(&translation)?,
+        formatter.notice(&translation),
+    ))
+}
+
+/// Batch mode: the options are validated once, then each line of
+/// stdin is translated with them.
+fn run_batch(user_input_options: &ArgMatches) -> ExitCode {
+    let (options, formatter, on_error) =
+        match validate_stream_options(user_input_options, StreamOutput::Lines) {
+            Ok(validated) => validated,
+            Err(e) => return report_failure(user_input_options, &Failure::from(e)),
+        };
+
+    let batch = Batch {
+        options: &options,
+        formatter: &formatter,
+        on_error,
+        json: user_input_options.get_flag("json"),
+        clock: &SystemClock,
     };
+    let output = io::BufWriter::new(io::stdout().lock());
+    match batch.run(io::stdin().lock(), output, io::stderr().lock()) {
+        Ok(code) => code,
+        Err(e) => report_failure(user_input_options, &Failure::from(e)),
+    }
+}
 
-    match TimezoneTranslator::new(request).convert() {
-        Ok(translated) => {
-            println!("{}", translated);
-            ExitCode::SUCCESS
+/// The table to read in table mode and its delimiter, if `--csv` or
+/// `--tsv` was given.
+fn table_source(user_input_options: &ArgMatches) -> Option<(&String, u8)> {
+    match (
+        user_input_options.get_one::<String>("csv"),
+        user_input_options.get_one::<String>("tsv"),
+    ) {
+        (Some(path), _) => Some((path, b',')),
+        (None, Some(path)) => Some((path, b'\t')),
+        (None, None) => None,
+    }
+}
+
+/// Table mode: the options are validated once, then the chosen
+/// columns of every row are translated with them. `-` reads stdin.
+fn run_table(user_input_options: &ArgMatches, path: &str, delimiter: u8) -> ExitCode {
+    let append: bool = user_input_options.get_flag("append_column");
+    let output = if append {
+        StreamOutput::AppendedColumns
+    } else {
+        StreamOutput::InPlace
+    };
+    let (options, formatter, on_error) = match validate_stream_options(user_input_options, output) {
+        Ok(validated) => validated,
+        Err(e) => return report_failure(user_input_options, &Failure::from(e)),
+    };
+    // clap requires --column alongside --csv and --tsv
+    let columns: Vec<ColumnSelector> = user_input_options
+        .get_many::<String>("column")
+        .unwrap()
+        .map(|text| text.as_str().into())
+        .collect();
+
+    let input: Box<dyn Read> = if path == "-" {
+        Box::new(io::stdin().lock())
+    } else {
+        match File::open(path) {
+            Ok(file) => Box::new(file),
+            Err(source) => {
+                let error = TableError::Open {
+                    path: path.to_string(),
+                    source,
+                };
+                return report_failure(user_input_options, &Failure::from(error));
+            }
         }
-        Err(e) => {
-            eprintln!("{}", e);
-            ExitCode::FAILURE
+    };
+
+    let table = Table {
+        options: &options,
+        formatter: &formatter,
+        columns: &columns,
+        delimiter,
+        append,
+        on_error,
+        clock: &SystemClock,
+    };
+    let output = io::BufWriter::new(io::stdout().lock());
+    match table.run(input, output, io::stderr().lock()) {
+        Ok(code) => code,
+        Err(e) => report_failure(user_input_options, &Failure::from(e)),
+    }
+}
+
+/// Rewrite mode: the options are validated once, then each line of
+/// stdin is written out, with its timestamps translated, as soon as
+/// it is read.
+fn run_rewrite(user_input_options: &ArgMatches) -> ExitCode {
+    let (options, formatter, on_error) =
+        match validate_stream_options(user_input_options, StreamOutput::InPlace) {
+            Ok(validated) => validated,
+            Err(e) => return report_failure(user_input_options, &Failure::from(e)),
+        };
+
+    let rewrite = Rewrite {
+        options: &options,
+        formatter: &formatter,
+        on_error,
+    };
+    // stdout is line-buffered, so each line leaves as soon as it is done
+    match rewrite.run(io::stdin().lock(), io::stdout().lock(), io::stderr().lock()) {
+        Ok(code) => code,
+        Err(e) => report_failure(user_input_options, &Failure::from(e)),
+    }
+}
+
+/// NDJSON mode: the options and field paths are validated once, then
+/// each document read from stdin is translated with them.
+fn run_ndjson(user_input_options: &ArgMatches) -> ExitCode {
+    let validated =
+        validate_stream_options(user_input_options, StreamOutput::InPlace).and_then(|options| {
+            // clap requires --field alongside --ndjson
+            let fields: Vec<FieldPath> = user_input_options
+                .get_many::<String>("field")
+                .unwrap()
+                .map(|text| text.parse())
+                .collect::<Result<_, _>>()?;
+            Ok((options, fields))
+        });
+    let ((options, formatter, on_error), fields) = match validated {
+        Ok(validated) => validated,
+        Err(e) => return report_failure(user_input_options, &Failure::from(e)),
+    };
+
+    let ndjson = Ndjson {
+        options: &options,
+        formatter: &formatter,
+        fields: &fields,
+        on_error,
+        clock: &SystemClock,
+    };
+    let output = io::BufWriter::new(io::stdout().lock());
+    match ndjson.run(io::stdin().lock(), output, io::stderr().lock()) {
+        Ok(code) => code,
+        Err(e) => report_failure(user_input_options, &Failure::from(e)),
+    }
+}
+
+/// `tzt list`: prints every timezone the filters admit, with its
+/// current offset and abbreviation.
+fn run_list(user_input_options: &ArgMatches, list_options: &ArgMatches) -> ExitCode {
+    let request = match validate_list_options(list_options) {
+        Ok(request) => request,
+        Err(e) => return report_failure(user_input_options, &Failure::from(e)),
+    };
+
+    let mut output = io::stdout().lock();
+    for line in render_zone_list(&TimezoneLister::new(request).list(&SystemClock)) {
+        // the list is often cut short (`tzt list | head`): stop quietly
+        if writeln!(output, "{}", line).is_err() {
+            break;
         }
     }
+    ExitCode::SUCCESS
+}
+
+/// `tzt info`: describes one timezone as it stands now. The failure
+/// is reported as JSON when `tzt info --json` asked for it.
+fn run_info(info_options: &ArgMatches) -> ExitCode {
+    let zone = match validate_info_options(info_options) {
+        Ok(zone) => zone,
+        Err(e) => return report_failure(info_options, &Failure::from(e)),
+    };
+
+    let info = ZoneInspector::new(zone).inspect(&SystemClock);
+    let lines: Vec<String> = if info_options.get_flag("json") {
+        vec![zone_info_json(&info).to_string()]
+    } else {
+        render_zone_info(&info)
+    };
+    let mut output = io::stdout().lock();
+    for line in lines {
+        // like `tzt list`, stop quietly when cut short (`tzt info UTC | head -1`)
+        if writeln!(output, "{}", line).is_err() {
+            break;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+/// Prints the failure to stderr (as JSON with `--json`) and returns
+/// its exit code.
+fn report_failure(user_input_options: &ArgMatches, failure: &Failure) -> ExitCode {
+    if user_input_options.get_flag("json") {
+        eprintln!("{}", failure_json(failure));
+    } else {
+        eprintln!("{}", failure);
+    }
+    ExitCode::from(failure.exit_code())
 }
@@ -0,0 +1,176 @@
+pub(crate) mod on_error;
+
+use self::on_error::OnError;
+use super::failure::Failure;
+use super::formatter::json_formatter::failure_json;
+use super::formatter::output_formatter::OutputFormatter;
+use super::render_text;
+use super::validator::command_options_validator::RequestOptions;
+use crate::domain::Clock;
+use serde_json::{json, Value};
+use std::io::{self, BufRead, Write};
+use std::ops::ControlFlow;
+use std::process::ExitCode;
+
+/// Translates every line of `input` with the same options, writing
+/// the results to `output` line by line (batch mode, `--stdin`).
+///
+/// A blank line is copied as it is, as NDJSON mode does. A line that
+/// fails is handled as `on_error` says; failures are reported to
+/// `errors` with their line number. With `fail`, the run stops there
+/// and exits with the failure's code.
+pub(crate) struct Batch<'a, C: Clock> {
+    pub(crate) options: &'a RequestOptions,
+    pub(crate) formatter: &'a OutputFormatter,
+    pub(crate) on_error: OnError,
+    pub(crate) json: bool,
+    pub(crate) clock: &'a C,
+}
+
+impl<C: Clock> Batch<'_, C> {
+    pub(crate) fn run(
+        &self,
+        input: impl BufRead,
+        mut output: impl Write,
+        mut errors: impl Write,
+    ) -> io::Result<ExitCode> {
+        for (index, line) in input.lines().enumerate() {
+            let line: String = line?;
+            if line.trim().is_empty() {
+                writeln!(output, "{}", line)?;
+                continue;
+            }
+            match render_text(self.options, self.formatter, line.trim(), self.clock) {
+                Ok((lines, notice)) => {
+                    if let Some(notice) = notice {
+                        writeln!(errors, "line {}: {}", index + 1, notice)?;
+                    }
+                    for rendered in lines {
+                        writeln!(output, "{}", rendered)?;
+                    }
+                }
+                Err(failure) => {
+                    writeln!(errors, "{}", self.report(index + 1, &failure))?;
+                    let flow = self
+                        .on_error
+                        .handle(&failure, || writeln!(output, "{}", line))?;
+                    if let ControlFlow::Break(code) = flow {
+                        output.flush()?;
+                        return Ok(code);
+                    }
+                }
+            }
+        }
+
+        output.flush()?;
+        Ok(ExitCode::SUCCESS)
+    }
+
+    /// The failure, prefixed with its 1-based line number.
+    fn report(&self, line_number: usize, failure: &Failure) -> String {
+        if self.json {
+            let mut report: Value = failure_json(failure);
+            report["line"] = json!(line_number);
+            report.to_string()
+        } else {
+            format!("line {}: {}", line_number, failure)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presentation::stream_fixture::{capture, clock, request_options};
+
+    /// Fixture: runs batch mode over `input` with the real CLI's
+    /// options, returning (exit code, stdout, stderr).
+    fn run_batch(args: &[&str], on_error: OnError, input: &str) -> (ExitCode, String, String) {
+        let batch = Batch {
+            options: &request_options(args),
+            formatter: &OutputFormatter::default(),
+            on_error,
+            json: false,
+            clock: &clock(),
+        };
+        capture(|output, errors| batch.run(input.as_bytes(), output, errors).unwrap())
+    }
+
+    #[test]
+    fn translates_each_line() {
+        // Arrange
+        let input = "2024-01-01 12:00:00\n2024-07-01 12:00:00\n";
+
+        // Act
+        let (code, output, _) = run_batch(&["-f", "UTC", "-t", "Asia/Tokyo"], OnError::Fail, input);
+
+        // Assert
+        assert_eq!(code, ExitCode::SUCCESS);
+        assert_eq!(output, "2024-01-01 21:00:00 JST\n2024-07-01 21:00:00 JST\n");
+    }
+
+    #[test]
+    fn stops_at_failing_line_with_fail() {
+        // Arrange
+        let input = "2024-01-01 12:00:00\nbad\n2024-07-01 12:00:00\n";
+
+        // Act
+        let (code, output, errors) =
+            run_batch(&["-f", "UTC", "-t", "Asia/Tokyo"], OnError::Fail, input);
+
+        // Assert
+        assert_eq!(code, ExitCode::from(3));
+        assert_eq!(output, "2024-01-01 21:00:00 JST\n");
+        assert!(errors.starts_with("line 2: Validation Error: Invalid time format found. bad"));
+    }
+
+    #[test]
+    fn copies_blank_line_between_timestamps() {
+        // Arrange
+        let input = "2024-01-01 12:00:00\n\n2024-07-01 12:00:00\n";
+
+        // Act
+        let (code, output, errors) =
+            run_batch(&["-f", "UTC", "-t", "Asia/Tokyo"], OnError::Fail, input);
+
+        // Assert
+        assert_eq!(code, ExitCode::SUCCESS);
+        assert_eq!(
+            output,
+            "2024-01-01 21:00:00 JST\n\n2024-07-01 21:00:00 JST\n"
+        );
+        assert_eq!(errors, "");
+    }
+
+    #[test]
+    fn drops_failing_line_with_skip() {
+        // Arrange
+        let input = "bad\n2024-07-01 12:00:00\n";
+
+        // Act
+        let (code, output, _) = run_batch(&["-f", "UTC", "-t", "Asia/Tokyo"], OnError::Skip, input);
+
+        // Assert
+        assert_eq!(code, ExitCode::SUCCESS);
+        assert_eq!(output, "2024-07-01 21:00:00 JST\n");
+    }
+
+    #[test]
+    fn echoes_failing_line_with_passthrough() {
+        // Arrange
+        // 02:30 on 2024-03-10 does not exist in New York (DST gap)
+        let input = "2024-03-10 02:30:00\n2024-03-10 03:30:00\n";
+
+        // Act
+        let (code, output, errors) = run_batch(
+            &["-f", "America/New_York", "-t", "UTC"],
+            OnError::Passthrough,
+            input,
+        );
+
+        // Assert
+        assert_eq!(code, ExitCode::SUCCESS);
+        assert_eq!(output, "2024-03-10 02:30:00\n2024-03-10 07:30:00 UTC\n");
+        assert!(errors.starts_with("line 1: Translation Error"));
+    }
+}
@@ -0,0 +1,145 @@
+use crate::presentation::failure::Failure;
+use std::ops::ControlFlow;
+use std::process::ExitCode;
+use std::str::FromStr;
+
+/// What a stream mode does with a line (row, document) that fails to
+/// translate (`--on-error`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum OnError {
+    /// Stop at the line, exiting with its failure's code.
+    #[default]
+    Fail,
+    /// Report the line on stderr and print nothing for it.
+    Skip,
+    /// Report the line on stderr and print it unchanged.
+    Passthrough,
+}
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error("Validation Error: Invalid on-error policy found. {0} (expected: skip, fail, passthrough)")]
+pub(crate) struct OnErrorParseError(pub String);
+
+impl OnError {
+    /// Deals with a line that failed with `failure`, after it was
+    /// reported: breaks with the failure's exit code to stop there, or
+    /// continues, copying the line with `pass_through` first when
+    /// passing it through.
+    pub(crate) fn handle<E>(
+        self,
+        failure: &Failure,
+        pass_through: impl FnOnce() -> Result<(), E>,
+    ) -> Result<ControlFlow<ExitCode>, E> {
+        match self {
+            OnError::Fail => return Ok(ControlFlow::Break(ExitCode::from(failure.exit_code()))),
+            OnError::Skip => {}
+            OnError::Passthrough => pass_through()?,
+        }
+        Ok(ControlFlow::Continue(()))
+    }
+}
+
+impl FromStr for OnError {
+    type Err = OnErrorParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "fail" => Ok(OnError::Fail),
+            "skip" => Ok(OnError::Skip),
+            "passthrough" => Ok(OnError::Passthrough),
+            _ => Err(OnErrorParseError(text.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ConversionTime;
+    use crate::presentation::validator::validation_error::ValidationError;
+
+    /// Fixture: the failure of an input that is not a time (exit code 3).
+    fn failure() -> Failure {
+        let error = "bad".parse::<ConversionTime>().unwrap_err();
+        Failure::from(ValidationError::from(error))
+    }
+
+    /// Fixture: handles `failure()` with `policy`, returning the
+    /// outcome and what was passed through.
+    fn handle(policy: OnError) -> (ControlFlow<ExitCode>, Vec<&'static str>) {
+        let mut copied: Vec<&'static str> = Vec::new();
+        let flow = policy
+            .handle(&failure(), || -> Result<(), ()> {
+                copied.push("bad");
+                Ok(())
+            })
+            .unwrap();
+        (flow, copied)
+    }
+
+    #[test]
+    fn stops_with_failure_exit_code() {
+        // Arrange
+        let policy = OnError::Fail;
+
+        // Act
+        let (flow, copied) = handle(policy);
+
+        // Assert
+        assert_eq!(flow, ControlFlow::Break(ExitCode::from(3)));
+        assert!(copied.is_empty());
+    }
+
+    #[test]
+    fn goes_on_without_copying_with_skip() {
+        // Arrange
+        let policy = OnError::Skip;
+
+        // Act
+        let (flow, copied) = handle(policy);
+
+        // Assert
+        assert_eq!(flow, ControlFlow::Continue(()));
+        assert!(copied.is_empty());
+    }
+
+    #[test]
+    fn copies_line_and_goes_on_with_passthrough() {
+        // Arrange
+        let policy = OnError::Passthrough;
+
+        // Act
+        let (flow, copied) = handle(policy);
+
+        // Assert
+        assert_eq!(flow, ControlFlow::Continue(()));
+        assert_eq!(copied, ["bad"]);
+    }
+
+    #[test]
+    fn parses_policy_names() {
+        // Arrange
+        let inputs = ["fail", "skip", "passthrough"];
+
+        // Act
+        let policies: Vec<OnError> = inputs.iter().map(|name| name.parse().unwrap()).collect();
+
+        // Assert
+        assert_eq!(
+            policies,
+            vec![OnError::Fail, OnError::Skip, OnError::Passthrough]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_policy() {
+        // Arrange
+        let input = "ignore";
+
+        // Act
+        let result: Result<OnError, OnErrorParseError> = input.parse();
+
+        // Assert
+        assert_eq!(result, Err(OnErrorParseError(input.to_string())));
+    }
+}
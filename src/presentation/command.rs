@@ -1,3 +1,5 @@
 mod arguments;
-mod command_definition;
+pub(super) mod command_definition;
+mod info_definition;
+mod list_definition;
 pub(super) mod receiver;
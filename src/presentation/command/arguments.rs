@@ -1,4 +1,24 @@
 pub(crate) mod ambiguous_time_strategy;
+pub(crate) mod append_column;
+pub(crate) mod column;
+pub(crate) mod csv;
+pub(crate) mod epoch_output;
+pub(crate) mod field;
+pub(crate) mod format;
 pub(crate) mod from_timezone;
+pub(crate) mod gap_strategy;
+pub(crate) mod input_format;
+pub(crate) mod json;
+pub(crate) mod ndjson;
+pub(crate) mod offset_value;
+pub(crate) mod on_error;
+pub(crate) mod output;
+pub(crate) mod precision;
+pub(crate) mod prefer_region;
+pub(crate) mod rewrite;
+pub(crate) mod stdin;
+pub(crate) mod strict;
 pub(crate) mod time;
+pub(crate) mod time_unit;
 pub(crate) mod to_timezone;
+pub(crate) mod tsv;
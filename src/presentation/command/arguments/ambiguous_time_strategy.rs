@@ -5,7 +5,7 @@ pub(crate) fn ambiguous_time_strategy() -> Arg {
         .short('a')
         .long("ambiguous-time-strategy")
         .value_name("STRATEGY")
-        .help("Strategy to use for ambiguous times (earliest, latest)")
+        .help("Strategy to use for ambiguous times (earliest, latest, error, both)")
         .default_value("earliest")
         .required(false)
 }
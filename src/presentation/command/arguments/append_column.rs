@@ -0,0 +1,10 @@
+use clap::{Arg, ArgAction};
+
+/// `--append-column`: keeps the translated columns and adds the
+/// results as new columns at the end of each row.
+pub(crate) fn append_column() -> Arg {
+    Arg::new("append_column")
+        .long("append-column")
+        .help("With --csv or --tsv, keep each --column and add its translation to every --to timezone as a new column")
+        .action(ArgAction::SetTrue)
+}
@@ -0,0 +1,14 @@
+use clap::{Arg, ArgAction};
+
+/// `--column`: the table columns to translate, by header name or
+/// 1-based position. Repeatable and comma-separated.
+pub(crate) fn column() -> Arg {
+    Arg::new("column")
+        .short('c')
+        .long("column")
+        .value_name("COLUMN")
+        .help("With --csv or --tsv, a column to translate, by header name or 1-based position; repeat or separate with commas for several")
+        .required(false)
+        .value_delimiter(',')
+        .action(ArgAction::Append)
+}
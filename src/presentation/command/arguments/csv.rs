@@ -0,0 +1,13 @@
+use clap::Arg;
+
+/// `--csv`: translates columns of a CSV file instead of `--time`.
+/// `-` reads the table from stdin.
+pub(crate) fn csv() -> Arg {
+    Arg::new("csv")
+        .long("csv")
+        .value_name("FILE")
+        .help("Read a CSV table from FILE (- for stdin) and translate the --column cells of every row")
+        .required(false)
+        .requires("column")
+        .conflicts_with_all(["time", "stdin", "json", "tsv"])
+}
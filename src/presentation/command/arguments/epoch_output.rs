@@ -0,0 +1,13 @@
+use clap::Arg;
+
+/// `--epoch-output`: prints the result as a Unix epoch value
+/// instead of a wall-clock time.
+pub(crate) fn epoch_output() -> Arg {
+    Arg::new("epoch_output")
+        .short('e')
+        .long("epoch-output")
+        .value_name("UNIT")
+        .help("Print the result as a Unix epoch value in this unit (s, ms, us, ns)")
+        .required(false)
+        .conflicts_with("precision")
+}
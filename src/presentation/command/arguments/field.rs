@@ -0,0 +1,13 @@
+use clap::{Arg, ArgAction};
+
+/// `--field`: the JSON fields to translate, as paths from the
+/// document root. Repeatable and comma-separated.
+pub(crate) fn field() -> Arg {
+    Arg::new("field")
+        .long("field")
+        .value_name("PATH")
+        .help("With --ndjson, the path of a field to translate (e.g. .ts or .request.started_at); repeat or separate with commas for several")
+        .required(false)
+        .value_delimiter(',')
+        .action(ArgAction::Append)
+}
@@ -0,0 +1,12 @@
+use clap::Arg;
+
+/// `--format`: a chrono strftime pattern to print the result with.
+pub(crate) fn format() -> Arg {
+    Arg::new("format")
+        .short('F')
+        .long("format")
+        .value_name("PATTERN")
+        .help("chrono strftime pattern to print the result with (e.g. %Y%m%dT%H%M%S)")
+        .required(false)
+        .conflicts_with_all(["precision", "epoch_output"])
+}
@@ -0,0 +1,12 @@
+use clap::Arg;
+
+/// `--gap-strategy`: what to do with a time skipped when DST starts.
+pub(crate) fn gap_strategy() -> Arg {
+    Arg::new("gap_strategy")
+        .short('g')
+        .long("gap-strategy")
+        .value_name("STRATEGY")
+        .help("Strategy to use for times skipped when DST starts (error, shift-forward, shift-backward, pre-transition-offset, post-transition-offset)")
+        .default_value("error")
+        .required(false)
+}
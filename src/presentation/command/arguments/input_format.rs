@@ -0,0 +1,13 @@
+use clap::{Arg, ArgAction};
+
+/// `--input-format`: extra chrono strftime patterns for reading `--time`.
+/// Repeatable; tried in order, before the built-in formats.
+pub(crate) fn input_format() -> Arg {
+    Arg::new("input_format")
+        .short('i')
+        .long("input-format")
+        .value_name("PATTERN")
+        .help("Extra chrono strftime pattern to read --time with (e.g. %d/%m/%Y %H:%M); repeatable, tried before the built-in formats")
+        .required(false)
+        .action(ArgAction::Append)
+}
@@ -0,0 +1,11 @@
+use clap::{Arg, ArgAction};
+
+/// `--json`: prints one JSON object describing the translation instead
+/// of a line per target timezone.
+pub(crate) fn json() -> Arg {
+    Arg::new("json")
+        .long("json")
+        .help("Print the translation as a JSON object (zones, wall times, offsets, abbreviations, DST)")
+        .action(ArgAction::SetTrue)
+        .conflicts_with_all(["precision", "epoch_output", "format", "output"])
+}
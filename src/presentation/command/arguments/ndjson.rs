@@ -0,0 +1,12 @@
+use clap::{Arg, ArgAction};
+
+/// `--ndjson`: translates `--field` values of the JSON documents read
+/// from stdin, one per line, instead of `--time`.
+pub(crate) fn ndjson() -> Arg {
+    Arg::new("ndjson")
+        .long("ndjson")
+        .help("Read JSON documents from stdin, one per line, and translate the string at each --field")
+        .action(ArgAction::SetTrue)
+        .requires("field")
+        .conflicts_with_all(["time", "time_unit", "stdin", "csv", "tsv", "rewrite", "json"])
+}
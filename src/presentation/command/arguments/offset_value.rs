@@ -0,0 +1,8 @@
+use clap::Arg;
+
+/// Lets `arg` take values that start with a hyphen, so that negative
+/// UTC offsets (`--from -05:00`, `tzt info -03:30`) are read as values
+/// rather than as unknown flags.
+pub(crate) fn offset_value(arg: Arg) -> Arg {
+    arg.allow_hyphen_values(true)
+}
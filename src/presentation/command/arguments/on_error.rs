@@ -0,0 +1,12 @@
+use clap::Arg;
+
+/// `--on-error`: what batch, table, rewrite and NDJSON modes do with a
+/// line that fails.
+pub(crate) fn on_error() -> Arg {
+    Arg::new("on_error")
+        .long("on-error")
+        .value_name("POLICY")
+        .help("With --stdin, --csv, --tsv, --rewrite or --ndjson, what to do with a line that fails (fail: stop, skip: drop it, passthrough: print it unchanged)")
+        .default_value("fail")
+        .required(false)
+}
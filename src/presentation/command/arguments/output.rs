@@ -0,0 +1,12 @@
+use clap::Arg;
+
+/// `--output`: a named standard layout to print the result with.
+pub(crate) fn output() -> Arg {
+    Arg::new("output")
+        .short('o')
+        .long("output")
+        .value_name("PRESET")
+        .help("Standard layout to print the result with (rfc3339, rfc2822, iso8601-basic, http-date, sql, epoch)")
+        .required(false)
+        .conflicts_with_all(["format", "epoch_output"])
+}
@@ -0,0 +1,14 @@
+use clap::Arg;
+
+/// `--precision`: fractional-second digits to print (0 to 9).
+/// Omitted, the output shows only the digits the value needs.
+pub(crate) fn precision() -> Arg {
+    Arg::new("precision")
+        .short('p')
+        .long("precision")
+        .value_name("DIGITS")
+        .help(
+            "Number of fractional-second digits to print, from 0 to 9 (default: as many as needed)",
+        )
+        .required(false)
+}
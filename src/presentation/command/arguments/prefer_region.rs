@@ -0,0 +1,12 @@
+use clap::Arg;
+
+/// `--prefer-region`: the IANA area that settles an ambiguous
+/// timezone abbreviation in `--from` / `--to` (e.g. `IST`).
+pub(crate) fn prefer_region() -> Arg {
+    Arg::new("prefer_region")
+        .short('r')
+        .long("prefer-region")
+        .value_name("REGION")
+        .help("Region to prefer when a timezone abbreviation is ambiguous (Africa, America, Antarctica, Arctic, Asia, Atlantic, Australia, Europe, Indian, Pacific)")
+        .required(false)
+}
@@ -0,0 +1,11 @@
+use clap::{Arg, ArgAction};
+
+/// `--rewrite`: replaces the timestamps found in lines read from stdin
+/// with their translations, instead of translating `--time`.
+pub(crate) fn rewrite() -> Arg {
+    Arg::new("rewrite")
+        .long("rewrite")
+        .help("Read lines (e.g. logs) from stdin and replace every timestamp in them with its translation, leaving the rest of each line intact")
+        .action(ArgAction::SetTrue)
+        .conflicts_with_all(["time", "time_unit", "stdin", "csv", "tsv", "json"])
+}
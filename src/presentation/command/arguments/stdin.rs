@@ -0,0 +1,11 @@
+use clap::{Arg, ArgAction};
+
+/// `--stdin`: translates every line of stdin instead of `--time`.
+/// `--time -` means the same.
+pub(crate) fn stdin() -> Arg {
+    Arg::new("stdin")
+        .long("stdin")
+        .help("Read times from stdin, one per line, and translate each (same as --time -)")
+        .action(ArgAction::SetTrue)
+        .conflicts_with("time")
+}
@@ -0,0 +1,10 @@
+use clap::{Arg, ArgAction};
+
+/// `--strict`: accepts only exact IANA names in `--from` / `--to`,
+/// without case-insensitive matching or "did you mean" suggestions.
+pub(crate) fn strict() -> Arg {
+    Arg::new("strict")
+        .long("strict")
+        .help("Require exact (case-sensitive) IANA timezone names and skip suggestions")
+        .action(ArgAction::SetTrue)
+}
@@ -1,12 +1,14 @@
 use clap::Arg;
 
+/// `--time`: the time to translate. Required unless the times are read
+/// from a stream (`--stdin`, `--csv`, `--tsv`, `--rewrite`, `--ndjson`).
 pub(crate) fn time() -> Arg {
     Arg::new("time")
         .short('T')
         .long("time")
         .value_name("TIME")
         .help(
-            "Time in the format YYYY-MM-DD HH:MM:SS (you can omit HH:MM:SS) or YYYY-MM-DDTHH:MM:SS",
+            "Time in the format YYYY-MM-DD HH:MM:SS[.fff] (you can omit HH:MM:SS) or YYYY-MM-DDTHH:MM:SS[.fff], optionally followed by a UTC offset (+09:00 or Z), Unix epoch seconds such as @1719491696, or a relative expression (now, tomorrow 09:00, next monday 14:30, now + 3h, in 90 minutes)",
         )
-        .required(true)
+        .required_unless_present_any(["stdin", "csv", "tsv", "rewrite", "ndjson"])
 }
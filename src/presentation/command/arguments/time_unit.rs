@@ -0,0 +1,12 @@
+use clap::Arg;
+
+/// `--time-unit`: reads `--time` as a Unix epoch value in this unit.
+/// Without it, only `@`-prefixed seconds are read as epoch.
+pub(crate) fn time_unit() -> Arg {
+    Arg::new("time_unit")
+        .short('u')
+        .long("time-unit")
+        .value_name("UNIT")
+        .help("Read --time as a Unix epoch value in this unit (s, ms, us, ns)")
+        .required(false)
+}
@@ -1,13 +1,16 @@
-use clap::Arg;
+use super::offset_value::offset_value;
+use clap::{Arg, ArgAction};
 
-/// `--to`: the timezone the time should be translated into.
+/// `--to`: the timezones the time should be translated into.
+/// Repeatable and comma-separated; one output line per timezone.
 /// Defaults to the local timezone detected at startup.
 pub(crate) fn to_timezone(default_timezone: String) -> Arg {
-    Arg::new("to_timezone")
+    offset_value(Arg::new("to_timezone"))
     .short('t')
     .long("to")
     .value_name("TO_TIMEZONE")
-    .help("The target timezone (e.g. Asia/Tokyo) @see https://docs.rs/chrono-tz/latest/chrono_tz/enum.Tz.html#")
+    .help("The target timezone (e.g. Asia/Tokyo, JST or +09:00); repeat or separate with commas for several @see https://docs.rs/chrono-tz/latest/chrono_tz/enum.Tz.html#")
     .required(false)
+    .action(ArgAction::Append)
     .default_value(default_timezone)
 }
@@ -0,0 +1,12 @@
+use clap::Arg;
+
+/// `--tsv`: as `--csv`, for tab-separated tables.
+pub(crate) fn tsv() -> Arg {
+    Arg::new("tsv")
+        .long("tsv")
+        .value_name("FILE")
+        .help("Read a tab-separated table from FILE (- for stdin) and translate the --column cells of every row")
+        .required(false)
+        .requires("column")
+        .conflicts_with_all(["time", "stdin", "json"])
+}
@@ -1,13 +1,23 @@
 use super::arguments::{
-    ambiguous_time_strategy::ambiguous_time_strategy, from_timezone::from_timezone, time::time,
-    to_timezone::to_timezone,
+    ambiguous_time_strategy::ambiguous_time_strategy, append_column::append_column, column::column,
+    csv::csv, epoch_output::epoch_output, field::field, format::format,
+    from_timezone::from_timezone, gap_strategy::gap_strategy, input_format::input_format,
+    json::json, ndjson::ndjson, on_error::on_error, output::output, precision::precision,
+    prefer_region::prefer_region, rewrite::rewrite, stdin::stdin, strict::strict, time::time,
+    time_unit::time_unit, to_timezone::to_timezone, tsv::tsv,
 };
+use super::info_definition::info_command;
+use super::list_definition::list_command;
 use crate::infrastructure::provide_local_timezone_string;
 use clap::Command;
 
 /// # About:
 /// Provides the command definition for the `tzt` command.
 ///
+/// Without a subcommand, `tzt` translates a time. `tzt list` browses
+/// the timezones and `tzt info` describes one; their options cannot be
+/// mixed with those of the translation.
+///
 /// # Returns:
 /// `Command` struct containing the command definition.
 ///
@@ -24,8 +34,31 @@ pub(crate) fn command_provider() -> Command {
         .version(env!("CARGO_PKG_VERSION"))
         .author("shunsock")
         .about("translate time from one timezone to another")
+        .subcommand(list_command())
+        .subcommand(info_command())
+        .args_conflicts_with_subcommands(true)
+        .subcommand_negates_reqs(true)
         .arg(time())
+        .arg(time_unit())
+        .arg(input_format())
         .arg(from_timezone(local_timezone.clone()))
         .arg(to_timezone(local_timezone))
+        .arg(prefer_region())
+        .arg(strict())
         .arg(ambiguous_time_strategy())
+        .arg(gap_strategy())
+        .arg(precision())
+        .arg(epoch_output())
+        .arg(format())
+        .arg(output())
+        .arg(json())
+        .arg(stdin())
+        .arg(on_error())
+        .arg(csv())
+        .arg(tsv())
+        .arg(column())
+        .arg(append_column())
+        .arg(rewrite())
+        .arg(ndjson())
+        .arg(field())
 }
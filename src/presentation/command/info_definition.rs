@@ -0,0 +1,31 @@
+use super::arguments::{offset_value::offset_value, prefer_region::prefer_region, strict::strict};
+use clap::{Arg, ArgAction, Command};
+
+/// # About:
+/// Provides the definition of the `tzt info` subcommand, which
+/// describes one timezone: its current offset and DST state, and the
+/// transitions before and after now.
+///
+/// The zone is matched like `--from`, with the same `--prefer-region`
+/// and `--strict`.
+///
+/// # Returns:
+/// `Command` struct containing the subcommand definition.
+pub(crate) fn info_command() -> Command {
+    Command::new("info")
+        .about("describe a timezone: current offset, DST status and transitions")
+        .arg(
+            offset_value(Arg::new("zone"))
+                .value_name("ZONE")
+                .help("The timezone to describe (e.g. Europe/London, BST or +01:00)")
+                .required(true),
+        )
+        .arg(prefer_region())
+        .arg(strict())
+        .arg(
+            Arg::new("json")
+                .long("json")
+                .help("Print the description as a JSON object")
+                .action(ArgAction::SetTrue),
+        )
+}
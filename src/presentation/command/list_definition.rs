@@ -0,0 +1,43 @@
+use super::arguments::offset_value::offset_value;
+use clap::{Arg, Command};
+
+/// # About:
+/// Provides the definition of the `tzt list` subcommand, which prints
+/// the IANA timezones with their current offset and abbreviation.
+///
+/// # Returns:
+/// `Command` struct containing the subcommand definition.
+pub(crate) fn list_command() -> Command {
+    Command::new("list")
+        .about("list timezones with their current UTC offset and abbreviation")
+        .arg(
+            Arg::new("pattern")
+                .value_name("PATTERN")
+                .help("Only timezones whose name contains PATTERN (case-insensitive, e.g. tokyo)")
+                .required(false),
+        )
+        .arg(
+            Arg::new("region")
+                .short('r')
+                .long("region")
+                .value_name("REGION")
+                .help("Only timezones in this IANA region (Africa, America, Antarctica, Arctic, Asia, Atlantic, Australia, Europe, Indian, Pacific)")
+                .required(false),
+        )
+        .arg(
+            offset_value(Arg::new("offset"))
+                .long("offset")
+                .value_name("OFFSET")
+                .help("Only timezones currently at this UTC offset (e.g. +09:00 or -03:30)")
+                .required(false),
+        )
+        .arg(
+            Arg::new("sort")
+                .short('s')
+                .long("sort")
+                .value_name("ORDER")
+                .help("Order of the list (name, offset)")
+                .default_value("name")
+                .required(false),
+        )
+}
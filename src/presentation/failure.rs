@@ -0,0 +1,290 @@
+use super::formatter::output_error::OutputError;
+use super::ndjson::ndjson_error::NdjsonError;
+use super::table::table_error::TableError;
+use super::validator::validation_error::ValidationError;
+use crate::domain::TimezoneParseError;
+use crate::usecase::translation_error::TranslationError;
+use std::{fmt, io};
+
+/// A failed run, as reported to the user and to the calling process.
+///
+/// Every `ValidationError`, `TranslationError`, `OutputError`,
+/// `TableError` and `NdjsonError` variant has its own `kind` and exit
+/// code, so wrappers can branch without parsing the message. Both are
+/// part of the CLI contract: keep them stable, and give a new kind the
+/// next free code of its group.
+///
+/// | codes | kinds |
+/// |-------|-------|
+/// | 1 | `io_error`: unexpected failures only |
+/// | 2 | clap's usage errors (unknown or conflicting flags) |
+/// | 3–9 | the time: `invalid_time` 3, `invalid_input_format` 4, `invalid_relative_time` 5, `invalid_epoch_time` 6, `invalid_epoch_unit` 7 |
+/// | 10–19 | timezones: `unknown_timezone` 10, `ambiguous_abbreviation` 11, `ambiguous_country` 12, `invalid_preferred_region` 13, `invalid_offset` 14 |
+/// | 20–29 | DST strategies: `invalid_ambiguous_time_strategy` 20, `invalid_gap_strategy` 21 |
+/// | 30–39 | output options: `invalid_precision` 30, `invalid_output_format` 31, `invalid_output_preset` 32, `invalid_sort` 33 |
+/// | 40–49 | stream modes: `invalid_on_error` 40, `invalid_field_path` 41, `unknown_column` 42, `invalid_stream_options` 43, `invalid_json` 44, `cannot_open_file` 45 |
+/// | 50–59 | translation: `nonexistent_time` 50, `ambiguous_time` 51, `offset_mismatch` 52 |
+/// | 60–69 | printing: `unprintable_time` 60, `year_out_of_range` 61 |
+///
+/// The README's "Exit Codes" table is the same list, with causes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Failure {
+    kind: &'static str,
+    exit_code: u8,
+    input: String,
+    message: String,
+}
+
+impl Failure {
+    /// Stable machine-readable name of the failure (`unknown_timezone`).
+    pub(crate) fn kind(&self) -> &'static str {
+        self.kind
+    }
+
+    pub(crate) fn exit_code(&self) -> u8 {
+        self.exit_code
+    }
+
+    /// The user input that caused the failure.
+    pub(crate) fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl From<ValidationError> for Failure {
+    fn from(error: ValidationError) -> Self {
+        let message = error.to_string();
+        let (kind, exit_code, input): (&'static str, u8, String) = match error {
+            ValidationError::Time(e) => ("invalid_time", 3, e.input),
+            ValidationError::InputFormat(e) => ("invalid_input_format", 4, e.0),
+            ValidationError::RelativeTime(e) => ("invalid_relative_time", 5, e.0),
+            ValidationError::EpochTime(e) => ("invalid_epoch_time", 6, e.0),
+            ValidationError::EpochUnit(e) => ("invalid_epoch_unit", 7, e.0),
+            ValidationError::Timezone(e) => match e {
+                TimezoneParseError::Unknown(input) => ("unknown_timezone", 10, input),
+                TimezoneParseError::Misspelled { input, .. } => ("unknown_timezone", 10, input),
+                TimezoneParseError::AmbiguousAbbreviation { abbreviation, .. } => {
+                    ("ambiguous_abbreviation", 11, abbreviation)
+                }
+                TimezoneParseError::AmbiguousCountry { country, .. } => {
+                    ("ambiguous_country", 12, country)
+                }
+            },
+            ValidationError::PreferredRegion(e) => ("invalid_preferred_region", 13, e.0),
+            ValidationError::UtcOffset(e) => ("invalid_offset", 14, e.0),
+            ValidationError::AmbiguousTimeStrategy(e) => {
+                ("invalid_ambiguous_time_strategy", 20, e.0)
+            }
+            ValidationError::GapTimeStrategy(e) => ("invalid_gap_strategy", 21, e.0),
+            ValidationError::OutputPrecision(e) => ("invalid_precision", 30, e.0),
+            ValidationError::OutputFormat(e) => ("invalid_output_format", 31, e.0),
+            ValidationError::OutputPreset(e) => ("invalid_output_preset", 32, e.0),
+            ValidationError::ListOrder(e) => ("invalid_sort", 33, e.0),
+            ValidationError::OnError(e) => ("invalid_on_error", 40, e.0),
+            ValidationError::FieldPath(e) => ("invalid_field_path", 41, e.0),
+            ValidationError::StreamOptions(_) => ("invalid_stream_options", 43, String::new()),
+        };
+        Failure {
+            kind,
+            exit_code,
+            input,
+            message,
+        }
+    }
+}
+
+impl From<TranslationError> for Failure {
+    fn from(error: TranslationError) -> Self {
+        let message = error.to_string();
+        let (kind, exit_code, input): (&'static str, u8, String) = match error {
+            TranslationError::NonexistentTime { time, .. } => {
+                ("nonexistent_time", 50, time.to_string())
+            }
+            TranslationError::AmbiguousTime { time, .. } => {
+                ("ambiguous_time", 51, time.to_string())
+            }
+            TranslationError::OffsetMismatch { time, offset, .. } => {
+                ("offset_mismatch", 52, format!("{}{}", time, offset))
+            }
+        };
+        Failure {
+            kind,
+            exit_code,
+            input,
+            message,
+        }
+    }
+}
+
+/// A stream that cannot be read or written, outside of any mode's own
+/// error type (batch and rewrite modes).
+impl From<io::Error> for Failure {
+    fn from(error: io::Error) -> Self {
+        Failure {
+            kind: "io_error",
+            exit_code: 1,
+            input: String::new(),
+            message: format!("IO Error: {}", error),
+        }
+    }
+}
+
+impl From<OutputError> for Failure {
+    fn from(error: OutputError) -> Self {
+        let message = error.to_string();
+        let (kind, exit_code, input): (&'static str, u8, String) = match error {
+            OutputError::Format(e) => ("unprintable_time", 60, e.format),
+            OutputError::YearOutOfRange { time, .. } => ("year_out_of_range", 61, time),
+        };
+        Failure {
+            kind,
+            exit_code,
+            input,
+            message,
+        }
+    }
+}
+
+impl From<TableError> for Failure {
+    fn from(error: TableError) -> Self {
+        let message = error.to_string();
+        let (kind, exit_code, input): (&'static str, u8, String) = match error {
+            TableError::UnknownColumn(column) => ("unknown_column", 42, column),
+            TableError::Open { path, .. } => ("cannot_open_file", 45, path),
+            TableError::Csv(_) | TableError::Io(_) => ("io_error", 1, String::new()),
+        };
+        Failure {
+            kind,
+            exit_code,
+            input,
+            message,
+        }
+    }
+}
+
+impl From<NdjsonError> for Failure {
+    fn from(error: NdjsonError) -> Self {
+        let message = error.to_string();
+        let (kind, exit_code): (&'static str, u8) = match error {
+            NdjsonError::InvalidJson(_) => ("invalid_json", 44),
+            NdjsonError::Io(_) => ("io_error", 1),
+        };
+        Failure {
+            kind,
+            exit_code,
+            input: String::new(),
+            message,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{SourceTimezone, Zone};
+    use chrono::NaiveDate;
+
+    #[test]
+    fn reports_unknown_timezone_with_its_input() {
+        // Arrange
+        let error = "Asia/Tokio".parse::<SourceTimezone>().unwrap_err();
+
+        // Act
+        let failure = Failure::from(ValidationError::from(error));
+
+        // Assert
+        assert_eq!(failure.kind(), "unknown_timezone");
+        assert_eq!(failure.exit_code(), 10);
+        assert_eq!(failure.input(), "Asia/Tokio");
+    }
+
+    #[test]
+    fn reports_nonexistent_time_with_its_input() {
+        // Arrange
+        let error = TranslationError::NonexistentTime {
+            time: NaiveDate::from_ymd_opt(2024, 3, 10)
+                .unwrap()
+                .and_hms_opt(2, 30, 0)
+                .unwrap(),
+            from_tz: Zone::Iana(chrono_tz::America::New_York),
+            gap: None,
+        };
+
+        // Act
+        let failure = Failure::from(error);
+
+        // Assert
+        assert_eq!(failure.kind(), "nonexistent_time");
+        assert_eq!(failure.exit_code(), 50);
+        assert_eq!(failure.input(), "2024-03-10 02:30:00");
+    }
+
+    #[test]
+    fn reports_unprintable_time_with_its_pattern() {
+        // Arrange
+        let error = OutputError::from(crate::domain::OutputFormatError {
+            format: "%Y".to_string(),
+            time: "2024-01-01T00:00:00Z".to_string(),
+        });
+
+        // Act
+        let failure = Failure::from(error);
+
+        // Assert
+        assert_eq!(failure.kind(), "unprintable_time");
+        assert_eq!(failure.exit_code(), 60);
+        assert_eq!(failure.input(), "%Y");
+    }
+
+    #[test]
+    fn reports_unopenable_table_with_its_path() {
+        // Arrange
+        let error = TableError::Open {
+            path: "missing.csv".to_string(),
+            source: io::Error::from(io::ErrorKind::NotFound),
+        };
+
+        // Act
+        let failure = Failure::from(error);
+
+        // Assert
+        assert_eq!(failure.kind(), "cannot_open_file");
+        assert_eq!(failure.exit_code(), 45);
+        assert_eq!(failure.input(), "missing.csv");
+    }
+
+    #[test]
+    fn reports_io_error_as_unexpected() {
+        // Arrange
+        let error = io::Error::from(io::ErrorKind::BrokenPipe);
+
+        // Act
+        let failure = Failure::from(error);
+
+        // Assert
+        assert_eq!(failure.kind(), "io_error");
+        assert_eq!(failure.exit_code(), 1);
+        assert!(failure.to_string().starts_with("IO Error: "));
+    }
+
+    #[test]
+    fn keeps_the_error_message() {
+        // Arrange
+        let error = "bad input"
+            .parse::<crate::domain::ConversionTime>()
+            .unwrap_err();
+        let message = error.to_string();
+
+        // Act
+        let failure = Failure::from(ValidationError::from(error));
+
+        // Assert
+        assert_eq!(failure.to_string(), message);
+    }
+}
@@ -0,0 +1,6 @@
+pub(crate) mod json_formatter;
+pub(crate) mod output_error;
+pub(crate) mod output_formatter;
+pub(crate) mod output_preset;
+pub(crate) mod zone_info_formatter;
+pub(crate) mod zone_list_formatter;
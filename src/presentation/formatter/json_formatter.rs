@@ -0,0 +1,213 @@
+use crate::domain::{RequestedTime, Zone, ZoneTransition};
+use crate::presentation::failure::Failure;
+use crate::usecase::translation::{Occurrence, Translation};
+use crate::usecase::zone_info::ZoneInfo;
+use chrono::{DateTime, Offset, SecondsFormat};
+use serde_json::{json, Value};
+
+/// Wall times are written without a zone; `utc_offset_seconds` says
+/// where they sit. `%.f` prints a fraction only when it is non-zero.
+const WALL_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+/// Renders a translation as JSON objects (`--json`), one per
+/// occurrence: two for an ambiguous time with the `both` strategy,
+/// one otherwise.
+///
+/// ```json
+/// {"input":"2024-01-01T12:00:00",
+///  "source":{"zone":"UTC","instant":"2024-01-01T12:00:00Z",...},
+///  "ambiguity_resolution":null,
+///  "gap_resolution":null,
+///  "targets":[{"zone":"Asia/Tokyo","wall_time":"2024-01-01T21:00:00",...}]}
+/// ```
+pub(crate) fn translation_json(translation: &Translation) -> Vec<Value> {
+    translation
+        .occurrences()
+        .iter()
+        .map(|occurrence| occurrence_json(translation.input(), occurrence))
+        .collect()
+}
+
+fn occurrence_json(input: RequestedTime, occurrence: &Occurrence) -> Value {
+    let source: &DateTime<Zone> = occurrence.source_time();
+
+    json!({
+        "input": input_json(input),
+        "source": {
+            "zone": source.timezone().to_string(),
+            "instant": source.to_utc().to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            "wall_time": source.naive_local().format(WALL_TIME_FORMAT).to_string(),
+            "utc_offset_seconds": source.offset().fix().local_minus_utc(),
+            "abbreviation": source.offset().abbreviation(),
+            "dst": source.offset().is_dst(),
+        },
+        "ambiguity_resolution": occurrence.ambiguity_resolution().map(|strategy| strategy.name()),
+        "gap_resolution": occurrence.gap_resolution().map(|strategy| strategy.name()),
+        "targets": occurrence.target_times().iter().map(time_json).collect::<Vec<Value>>(),
+    })
+}
+
+/// Renders a failure as one JSON object (`--json`), e.g.
+/// `{"kind":"unknown_timezone","input":"Asia/Tokio","exit_code":10,"message":"..."}`.
+pub(crate) fn failure_json(failure: &Failure) -> Value {
+    json!({
+        "kind": failure.kind(),
+        "input": failure.input(),
+        "exit_code": failure.exit_code(),
+        "message": failure.to_string(),
+    })
+}
+
+/// Renders the description of `tzt info --json`. Offsets are in
+/// seconds east of UTC; absent transitions and daylight offsets are
+/// `null`.
+///
+/// ```json
+/// {"zone":"Europe/London",
+///  "now":{"zone":"Europe/London","wall_time":"2024-06-27T13:00:00",...},
+///  "standard_offset_seconds":0,
+///  "daylight_offset_seconds":3600,
+///  "previous_transition":{"instant":"2024-03-31T01:00:00Z","before":{...},"after":{...}},
+///  "next_transition":{...}}
+/// ```
+pub(crate) fn zone_info_json(info: &ZoneInfo) -> Value {
+    json!({
+        "zone": info.zone().to_string(),
+        "now": time_json(info.now()),
+        "standard_offset_seconds": info.standard_offset().local_minus_utc(),
+        "daylight_offset_seconds": info.daylight_offset().map(|offset| offset.local_minus_utc()),
+        "previous_transition": info.previous_transition().map(transition_json),
+        "next_transition": info.next_transition().map(transition_json),
+    })
+}
+
+fn transition_json(transition: &ZoneTransition) -> Value {
+    json!({
+        "instant": transition.instant().to_rfc3339_opts(SecondsFormat::AutoSi, true),
+        "before": time_json(&transition.before()),
+        "after": time_json(&transition.after()),
+    })
+}
+
+/// The zone, wall time, offset, abbreviation and DST state of one time.
+pub(crate) fn time_json(time: &DateTime<Zone>) -> Value {
+    json!({
+        "zone": time.timezone().to_string(),
+        "wall_time": time.naive_local().format(WALL_TIME_FORMAT).to_string(),
+        "utc_offset_seconds": time.offset().fix().local_minus_utc(),
+        "abbreviation": time.offset().abbreviation(),
+        "dst": time.offset().is_dst(),
+    })
+}
+
+/// The time as the user wrote it: a wall time (with its offset, if it
+/// carried one), or the instant an epoch value names.
+fn input_json(input: RequestedTime) -> String {
+    match input {
+        RequestedTime::WallClock(time) => {
+            let wall_time = time.naive_datetime().format(WALL_TIME_FORMAT);
+            match time.offset() {
+                Some(offset) => format!("{}{}", wall_time, offset),
+                None => wall_time.to_string(),
+            }
+        }
+        RequestedTime::Epoch(time) => time.instant().to_rfc3339_opts(SecondsFormat::AutoSi, true),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{AmbiguousTimeStrategy, ConversionTime};
+    use crate::presentation::stream_fixture::clock;
+    use crate::usecase::zone_inspector::ZoneInspector;
+    use chrono::TimeZone;
+
+    fn new_york_fall_back_translation() -> Translation {
+        let new_york = Zone::Iana(chrono_tz::America::New_York);
+        let tokyo = Zone::Iana(chrono_tz::Asia::Tokyo);
+        // 01:30 on 2024-11-03 occurs twice in New York; the latest is EST
+        let source = new_york
+            .with_ymd_and_hms(2024, 11, 3, 1, 30, 0)
+            .latest()
+            .unwrap();
+        Translation::new(
+            "2024-11-03 01:30:00"
+                .parse::<ConversionTime>()
+                .unwrap()
+                .into(),
+            vec![Occurrence::new(source, vec![source.with_timezone(&tokyo)])
+                .with_ambiguity_resolution(AmbiguousTimeStrategy::Latest)],
+        )
+    }
+
+    #[test]
+    fn describes_source_and_resolution() {
+        // Arrange
+        let translation = new_york_fall_back_translation();
+
+        // Act
+        let output = &translation_json(&translation)[0];
+
+        // Assert
+        assert_eq!(output["input"], "2024-11-03T01:30:00");
+        assert_eq!(output["source"]["zone"], "America/New_York");
+        assert_eq!(output["source"]["instant"], "2024-11-03T06:30:00Z");
+        assert_eq!(output["source"]["abbreviation"], "EST");
+        assert_eq!(output["ambiguity_resolution"], "latest");
+    }
+
+    #[test]
+    fn describes_each_target() {
+        // Arrange
+        let translation = new_york_fall_back_translation();
+
+        // Act
+        let output = &translation_json(&translation)[0];
+
+        // Assert
+        assert_eq!(
+            output["targets"],
+            json!([{
+                "zone": "Asia/Tokyo",
+                "wall_time": "2024-11-03T15:30:00",
+                "utc_offset_seconds": 32400,
+                "abbreviation": "JST",
+                "dst": false,
+            }])
+        );
+    }
+
+    #[test]
+    fn reports_dst_in_effect() {
+        // Arrange
+        let time = Zone::Iana(chrono_tz::Europe::London)
+            .with_ymd_and_hms(2024, 7, 1, 12, 0, 0)
+            .unwrap();
+
+        // Act
+        let output = time_json(&time);
+
+        // Assert
+        assert_eq!(output["abbreviation"], "BST");
+        assert_eq!(output["utc_offset_seconds"], 3600);
+        assert_eq!(output["dst"], true);
+    }
+
+    #[test]
+    fn describes_zone_info_and_transitions() {
+        // Arrange
+        let info = ZoneInspector::new("Asia/Tokyo".parse().unwrap()).inspect(&clock());
+
+        // Act
+        let output = zone_info_json(&info);
+
+        // Assert
+        assert_eq!(output["zone"], "Asia/Tokyo");
+        assert_eq!(output["now"]["abbreviation"], "JST");
+        assert_eq!(output["standard_offset_seconds"], 32400);
+        assert_eq!(output["daylight_offset_seconds"], Value::Null);
+        assert_eq!(output["previous_transition"], Value::Null);
+        assert_eq!(output["next_transition"], Value::Null);
+    }
+}
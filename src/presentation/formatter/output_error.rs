@@ -0,0 +1,12 @@
+use crate::domain::OutputFormatError;
+
+/// Raised when a translated time cannot be printed in the chosen
+/// layout. The options were valid; this particular time is not.
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub(crate) enum OutputError {
+    #[error(transparent)]
+    Format(#[from] OutputFormatError),
+
+    #[error("Output Error: {time} cannot be printed as {preset}, whose years run from 0 to 9999. Use --output epoch instead.")]
+    YearOutOfRange { preset: &'static str, time: String },
+}
@@ -0,0 +1,186 @@
+use super::json_formatter::{time_json, translation_json};
+use super::output_error::OutputError;
+use super::output_preset::OutputPreset;
+use crate::domain::{EpochUnit, OutputFormat, OutputPrecision, Zone};
+use crate::usecase::translation::Translation;
+use chrono::{DateTime, Timelike};
+
+/// What the printed line looks like.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(crate) enum OutputLayout {
+    /// chrono's `Display` form (`2024-01-01 17:00:00 UTC`).
+    #[default]
+    Display,
+    /// The Unix epoch value of the instant (`--epoch-output`).
+    Epoch(EpochUnit),
+    /// A user strftime pattern (`--format`).
+    Pattern(OutputFormat),
+    /// A named standard layout (`--output`).
+    Preset(OutputPreset),
+    /// One JSON object describing the whole translation (`--json`).
+    Json,
+}
+
+/// Renders a translated time as the line printed to stdout.
+///
+/// The default layout shows fractional seconds only when they are
+/// non-zero; a precision fixes the number of digits for the layouts
+/// that print a fraction.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(crate) struct OutputFormatter {
+    precision: Option<OutputPrecision>,
+    layout: OutputLayout,
+}
+
+impl OutputFormatter {
+    pub(crate) fn new(precision: Option<OutputPrecision>, layout: OutputLayout) -> Self {
+        Self { precision, layout }
+    }
+
+    /// The lines printed for a translation: one per target timezone,
+    /// or one JSON object covering every target, for each occurrence.
+    ///
+    /// When an ambiguous time resolved to both occurrences, each line
+    /// is prefixed with the one it belongs to (`earliest: `,
+    /// `latest: `), as JSON's `ambiguity_resolution` says.
+    ///
+    /// Returns `OutputError` when a time cannot be printed in the
+    /// layout.
+    pub(crate) fn render(&self, translation: &Translation) -> Result<Vec<String>, OutputError> {
+        if self.layout == OutputLayout::Json {
+            return Ok(translation_json(translation)
+                .iter()
+                .map(|json| json.to_string())
+                .collect());
+        }
+
+        let labelled: bool = translation.occurrences().len() > 1;
+        let mut lines: Vec<String> = Vec::new();
+        for occurrence in translation.occurrences() {
+            for time in occurrence.target_times() {
+                let line: String = self.format(time)?;
+                lines.push(match occurrence.ambiguity_resolution() {
+                    Some(strategy) if labelled => format!("{}: {}", strategy.name(), line),
+                    _ => line,
+                });
+            }
+        }
+        Ok(lines)
+    }
+
+    /// A line for stderr when the source time had to be adjusted
+    /// because it fell into a DST gap. JSON reports this itself.
+    pub(crate) fn notice(&self, translation: &Translation) -> Option<String> {
+        let (occurrence, strategy) = translation
+            .occurrences()
+            .iter()
+            .find_map(|occurrence| Some((occurrence, occurrence.gap_resolution()?)))?;
+        match self.layout {
+            OutputLayout::Json => None,
+            _ => Some(format!(
+                "Notice: The requested time does not exist in {} (DST gap). Adjusted to {} ({}).",
+                occurrence.source_time().timezone(),
+                occurrence.source_time(),
+                strategy.name()
+            )),
+        }
+    }
+
+    pub(crate) fn format(&self, time: &DateTime<Zone>) -> Result<String, OutputError> {
+        Ok(match (&self.layout, self.precision) {
+            (OutputLayout::Json, _) => time_json(time).to_string(),
+            (OutputLayout::Epoch(unit), _) => unit.count_of(time).to_string(),
+            (OutputLayout::Pattern(format), _) => format.format(time)?,
+            (OutputLayout::Preset(preset), precision) => preset.format(time, precision)?,
+            (OutputLayout::Display, None) => time.to_string(),
+            (OutputLayout::Display, Some(precision)) => format!(
+                "{}{} {}",
+                time.format("%Y-%m-%d %H:%M:%S"),
+                precision.fraction_of(time.nanosecond()),
+                time.format("%Z")
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn tokyo_datetime_with_nanos(nanos: u32) -> DateTime<Zone> {
+        Zone::Iana(chrono_tz::Asia::Tokyo)
+            .with_ymd_and_hms(2024, 1, 1, 21, 0, 0)
+            .unwrap()
+            .with_nanosecond(nanos)
+            .unwrap()
+    }
+
+    #[test]
+    fn uses_display_form_without_precision() {
+        // Arrange
+        let formatter = OutputFormatter::default();
+
+        // Act
+        let output = formatter.format(&tokyo_datetime_with_nanos(0)).unwrap();
+
+        // Assert
+        assert_eq!(output, "2024-01-01 21:00:00 JST");
+    }
+
+    #[test]
+    fn prints_fixed_number_of_fractional_digits() {
+        // Arrange
+        let formatter = OutputFormatter::new(Some("6".parse().unwrap()), OutputLayout::Display);
+
+        // Act
+        let output = formatter
+            .format(&tokyo_datetime_with_nanos(789_000_000))
+            .unwrap();
+
+        // Assert
+        assert_eq!(output, "2024-01-01 21:00:00.789000 JST");
+    }
+
+    #[test]
+    fn prints_epoch_value_in_requested_unit() {
+        // Arrange
+        let formatter = OutputFormatter::new(None, OutputLayout::Epoch(EpochUnit::Milliseconds));
+
+        // Act
+        // 2024-01-01 21:00:00 JST is 1704110400 seconds after the epoch
+        let output = formatter
+            .format(&tokyo_datetime_with_nanos(789_000_000))
+            .unwrap();
+
+        // Assert
+        assert_eq!(output, "1704110400789");
+    }
+
+    #[test]
+    fn prints_with_user_output_format() {
+        // Arrange
+        let formatter = OutputFormatter::new(
+            None,
+            OutputLayout::Pattern("%Y%m%dT%H%M%S".parse().unwrap()),
+        );
+
+        // Act
+        let output = formatter.format(&tokyo_datetime_with_nanos(0)).unwrap();
+
+        // Assert
+        assert_eq!(output, "20240101T210000");
+    }
+
+    #[test]
+    fn prints_with_output_preset() {
+        // Arrange
+        let formatter = OutputFormatter::new(None, OutputLayout::Preset(OutputPreset::Rfc3339));
+
+        // Act
+        let output = formatter.format(&tokyo_datetime_with_nanos(0)).unwrap();
+
+        // Assert
+        assert_eq!(output, "2024-01-01T21:00:00+09:00");
+    }
+}
@@ -0,0 +1,330 @@
+use super::output_error::OutputError;
+use crate::domain::{EpochUnit, OutputPrecision};
+use chrono::{DateTime, Datelike, TimeZone, Timelike, Utc};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A named standard layout for printing the result (`--output`).
+///
+/// Layouts with fractional seconds take `--precision`; without it they
+/// print the fraction only when it is non-zero, as 3, 6 or 9 digits.
+/// RFC 2822 and HTTP dates have whole seconds by definition.
+///
+/// Every layout but `epoch` writes the year as exactly four digits, so
+/// it prints only the years 0 to 9999.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OutputPreset {
+    /// `2024-01-01T21:00:00+09:00`
+    Rfc3339,
+    /// `Mon, 1 Jan 2024 21:00:00 +0900`
+    Rfc2822,
+    /// `20240101T210000+0900`
+    Iso8601Basic,
+    /// `Mon, 01 Jan 2024 12:00:00 GMT`, always in GMT (RFC 9110)
+    HttpDate,
+    /// `2024-01-01 21:00:00`, without a zone
+    Sql,
+    /// `1704110400`, Unix epoch seconds
+    Epoch,
+}
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error("Validation Error: Invalid output preset found. {0} (expected: rfc3339, rfc2822, iso8601-basic, http-date, sql, epoch)")]
+pub(crate) struct OutputPresetParseError(pub String);
+
+impl FromStr for OutputPreset {
+    type Err = OutputPresetParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "rfc3339" => Ok(OutputPreset::Rfc3339),
+            "rfc2822" => Ok(OutputPreset::Rfc2822),
+            "iso8601-basic" => Ok(OutputPreset::Iso8601Basic),
+            "http-date" => Ok(OutputPreset::HttpDate),
+            "sql" => Ok(OutputPreset::Sql),
+            "epoch" => Ok(OutputPreset::Epoch),
+            _ => Err(OutputPresetParseError(text.to_string())),
+        }
+    }
+}
+
+/// The years a four-digit year field can hold.
+const FOUR_DIGIT_YEARS: std::ops::RangeInclusive<i32> = 0..=9999;
+
+impl OutputPreset {
+    /// The name `--output` takes.
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            OutputPreset::Rfc3339 => "rfc3339",
+            OutputPreset::Rfc2822 => "rfc2822",
+            OutputPreset::Iso8601Basic => "iso8601-basic",
+            OutputPreset::HttpDate => "http-date",
+            OutputPreset::Sql => "sql",
+            OutputPreset::Epoch => "epoch",
+        }
+    }
+
+    /// Renders `time` in this layout.
+    ///
+    /// Returns `OutputError` when the layout has a four-digit year and
+    /// the printed year does not fit it.
+    pub(crate) fn format<Tz: TimeZone>(
+        &self,
+        time: &DateTime<Tz>,
+        precision: Option<OutputPrecision>,
+    ) -> Result<String, OutputError>
+    where
+        Tz::Offset: Display,
+    {
+        self.check_year(time)?;
+        let fraction = fraction_of(time.nanosecond(), precision);
+        Ok(match self {
+            OutputPreset::Rfc3339 => format!(
+                "{}{}{}",
+                time.format("%Y-%m-%dT%H:%M:%S"),
+                fraction,
+                time.format("%:z")
+            ),
+            OutputPreset::Rfc2822 => time.to_rfc2822(),
+            OutputPreset::Iso8601Basic => format!(
+                "{}{}{}",
+                time.format("%Y%m%dT%H%M%S"),
+                fraction,
+                time.format("%z")
+            ),
+            OutputPreset::HttpDate => time
+                .with_timezone(&Utc)
+                .format("%a, %d %b %Y %H:%M:%S GMT")
+                .to_string(),
+            OutputPreset::Sql => format!("{}{}", time.format("%Y-%m-%d %H:%M:%S"), fraction),
+            OutputPreset::Epoch => epoch_seconds_of(time, precision),
+        })
+    }
+
+    /// Fails when the year this layout would print (in GMT for HTTP
+    /// dates) has more or fewer than four digits.
+    fn check_year<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Result<(), OutputError>
+    where
+        Tz::Offset: Display,
+    {
+        let year: i32 = match self {
+            OutputPreset::Epoch => return Ok(()),
+            OutputPreset::HttpDate => time.with_timezone(&Utc).year(),
+            _ => time.year(),
+        };
+        if FOUR_DIGIT_YEARS.contains(&year) {
+            return Ok(());
+        }
+        Err(OutputError::YearOutOfRange {
+            preset: self.name(),
+            time: time.to_rfc3339(),
+        })
+    }
+}
+
+/// `.789` style fraction: fixed digits with a precision, otherwise
+/// chrono's shortest of 3, 6 or 9 digits (nothing when zero).
+fn fraction_of(nanos: u32, precision: Option<OutputPrecision>) -> String {
+    match precision {
+        Some(precision) => precision.fraction_of(nanos),
+        None => {
+            let nanos = nanos % 1_000_000_000;
+            match nanos {
+                0 => String::new(),
+                _ if nanos.is_multiple_of(1_000_000) => format!(".{:03}", nanos / 1_000_000),
+                _ if nanos.is_multiple_of(1_000) => format!(".{:06}", nanos / 1_000),
+                _ => format!(".{:09}", nanos),
+            }
+        }
+    }
+}
+
+/// Epoch seconds, with the fraction after a decimal point. The sign is
+/// kept apart so that half a second before the epoch reads `-0.5`.
+fn epoch_seconds_of<Tz: TimeZone>(
+    time: &DateTime<Tz>,
+    precision: Option<OutputPrecision>,
+) -> String {
+    let nanos: i128 = EpochUnit::Nanoseconds.count_of(time);
+    let sign = if nanos < 0 { "-" } else { "" };
+    let seconds = nanos.unsigned_abs() / 1_000_000_000;
+    let subsecond = (nanos.unsigned_abs() % 1_000_000_000) as u32;
+    format!("{}{}{}", sign, seconds, fraction_of(subsecond, precision))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokyo_datetime_with_nanos(nanos: u32) -> DateTime<chrono_tz::Tz> {
+        chrono_tz::Asia::Tokyo
+            .with_ymd_and_hms(2024, 1, 1, 21, 0, 0)
+            .unwrap()
+            .with_nanosecond(nanos)
+            .unwrap()
+    }
+
+    #[test]
+    fn parses_preset_names() {
+        // Arrange
+        let inputs = [
+            "rfc3339",
+            "rfc2822",
+            "iso8601-basic",
+            "http-date",
+            "sql",
+            "epoch",
+        ];
+
+        // Act
+        let presets: Vec<OutputPreset> = inputs.iter().map(|name| name.parse().unwrap()).collect();
+
+        // Assert
+        assert_eq!(
+            presets,
+            vec![
+                OutputPreset::Rfc3339,
+                OutputPreset::Rfc2822,
+                OutputPreset::Iso8601Basic,
+                OutputPreset::HttpDate,
+                OutputPreset::Sql,
+                OutputPreset::Epoch,
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_preset() {
+        // Arrange
+        let input = "rfc9999";
+
+        // Act
+        let result: Result<OutputPreset, OutputPresetParseError> = input.parse();
+
+        // Assert
+        assert_eq!(result, Err(OutputPresetParseError(input.to_string())));
+    }
+
+    #[test]
+    fn prints_shortest_millisecond_fraction_without_precision() {
+        // Arrange
+        let preset = OutputPreset::Rfc3339;
+
+        // Act
+        let output = preset
+            .format(&tokyo_datetime_with_nanos(789_000_000), None)
+            .unwrap();
+
+        // Assert
+        assert_eq!(output, "2024-01-01T21:00:00.789+09:00");
+    }
+
+    #[test]
+    fn prints_fixed_fraction_with_precision() {
+        // Arrange
+        let preset = OutputPreset::Iso8601Basic;
+
+        // Act
+        let output = preset
+            .format(
+                &tokyo_datetime_with_nanos(789_000_000),
+                Some("6".parse().unwrap()),
+            )
+            .unwrap();
+
+        // Assert
+        assert_eq!(output, "20240101T210000.789000+0900");
+    }
+
+    #[test]
+    fn prints_negative_epoch_with_fraction() {
+        // Arrange
+        let half_second_before_epoch = Utc.timestamp_opt(-1, 500_000_000).unwrap();
+
+        // Act
+        let output = OutputPreset::Epoch
+            .format(&half_second_before_epoch, None)
+            .unwrap();
+
+        // Assert
+        assert_eq!(output, "-0.500");
+    }
+
+    /// Fixture: every layout that writes a four-digit year.
+    const FOUR_DIGIT_YEAR_PRESETS: [OutputPreset; 5] = [
+        OutputPreset::Rfc3339,
+        OutputPreset::Rfc2822,
+        OutputPreset::Iso8601Basic,
+        OutputPreset::HttpDate,
+        OutputPreset::Sql,
+    ];
+
+    #[test]
+    fn prints_first_and_last_four_digit_years() {
+        // Arrange
+        let first = Utc.with_ymd_and_hms(0, 1, 1, 0, 0, 0).unwrap();
+        let last = Utc.with_ymd_and_hms(9999, 12, 31, 23, 59, 59).unwrap();
+
+        // Act
+        let printed: Vec<bool> = FOUR_DIGIT_YEAR_PRESETS
+            .iter()
+            .flat_map(|preset| [preset.format(&first, None), preset.format(&last, None)])
+            .map(|result| result.is_ok())
+            .collect();
+
+        // Assert
+        assert_eq!(printed, [true; 10]);
+    }
+
+    #[test]
+    fn rejects_years_outside_four_digits() {
+        // Arrange
+        let before = Utc.with_ymd_and_hms(-1, 12, 31, 23, 59, 59).unwrap();
+        let after = Utc.with_ymd_and_hms(10000, 1, 1, 0, 0, 0).unwrap();
+
+        // Act
+        let errors: Vec<OutputError> = FOUR_DIGIT_YEAR_PRESETS
+            .iter()
+            .flat_map(|preset| [preset.format(&before, None), preset.format(&after, None)])
+            .map(|result| result.unwrap_err())
+            .collect();
+
+        // Assert
+        assert_eq!(errors.len(), 10);
+        assert_eq!(
+            errors[1],
+            OutputError::YearOutOfRange {
+                preset: "rfc3339",
+                time: "+10000-01-01T00:00:00+00:00".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn checks_http_date_year_in_gmt() {
+        // Arrange
+        // 10000-01-01 08:00 in Tokyo is still 9999-12-31 23:00 in GMT
+        let tokyo = chrono_tz::Asia::Tokyo
+            .with_ymd_and_hms(10000, 1, 1, 8, 0, 0)
+            .unwrap();
+
+        // Act
+        let result = OutputPreset::HttpDate.format(&tokyo, None);
+
+        // Assert
+        assert_eq!(result.unwrap(), "Fri, 31 Dec 9999 23:00:00 GMT");
+    }
+
+    #[test]
+    fn prints_epoch_of_any_year() {
+        // Arrange
+        // @999999999999 is in the year 33658
+        let far_future = Utc.timestamp_opt(999_999_999_999, 0).unwrap();
+
+        // Act
+        let result = OutputPreset::Epoch.format(&far_future, None);
+
+        // Assert
+        assert_eq!(result.unwrap(), "999999999999");
+    }
+}
@@ -0,0 +1,89 @@
+use crate::domain::ZoneTransition;
+use crate::usecase::zone_info::ZoneInfo;
+use chrono::SecondsFormat;
+
+/// Renders the description of `tzt info`, one labelled line each.
+/// A transition shows its instant, then the wall clock just before and
+/// at it.
+///
+/// ```text
+/// Zone:                 Europe/London
+/// Now:                  2024-06-27 13:00:00 BST (+01:00)
+/// DST:                  yes
+/// Standard offset:      +00:00
+/// Daylight offset:      +01:00
+/// Previous transition:  2024-03-31T01:00:00Z  2024-03-31 00:59:59 GMT -> 2024-03-31 02:00:00 BST
+/// Next transition:      2024-10-27T01:00:00Z  2024-10-27 01:59:59 BST -> 2024-10-27 01:00:00 GMT
+/// ```
+pub(crate) fn render_zone_info(info: &ZoneInfo) -> Vec<String> {
+    let rows: [(&str, String); 7] = [
+        ("Zone", info.zone().to_string()),
+        (
+            "Now",
+            format!("{} ({})", info.now(), info.now().format("%:z")),
+        ),
+        ("DST", if info.is_dst() { "yes" } else { "no" }.to_string()),
+        ("Standard offset", info.standard_offset().to_string()),
+        (
+            "Daylight offset",
+            info.daylight_offset()
+                .map_or("none".to_string(), |offset| offset.to_string()),
+        ),
+        (
+            "Previous transition",
+            describe_transition(info.previous_transition()),
+        ),
+        (
+            "Next transition",
+            describe_transition(info.next_transition()),
+        ),
+    ];
+
+    rows.iter()
+        .map(|(label, value)| format!("{:<22}{}", format!("{}:", label), value))
+        .collect()
+}
+
+fn describe_transition(transition: Option<&ZoneTransition>) -> String {
+    match transition {
+        Some(transition) => format!(
+            "{}  {} -> {}",
+            transition
+                .instant()
+                .to_rfc3339_opts(SecondsFormat::Secs, true),
+            transition.before(),
+            transition.after(),
+        ),
+        None => "none".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presentation::stream_fixture::clock;
+    use crate::usecase::zone_inspector::ZoneInspector;
+
+    #[test]
+    fn describes_zone_line_by_line() {
+        // Arrange
+        let info = ZoneInspector::new("Europe/London".parse().unwrap()).inspect(&clock());
+
+        // Act
+        let lines = render_zone_info(&info);
+
+        // Assert
+        assert_eq!(
+            lines,
+            [
+                "Zone:                 Europe/London",
+                "Now:                  2024-06-27 13:00:00 BST (+01:00)",
+                "DST:                  yes",
+                "Standard offset:      +00:00",
+                "Daylight offset:      +01:00",
+                "Previous transition:  2024-03-31T01:00:00Z  2024-03-31 00:59:59 GMT -> 2024-03-31 02:00:00 BST",
+                "Next transition:      2024-10-27T01:00:00Z  2024-10-27 01:59:59 BST -> 2024-10-27 01:00:00 GMT",
+            ]
+        );
+    }
+}
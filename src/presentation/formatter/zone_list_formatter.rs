@@ -0,0 +1,64 @@
+use crate::domain::Zone;
+use chrono::DateTime;
+
+/// Renders the timezones of `tzt list`, one line each: the name,
+/// padded so that the columns line up, then the current UTC offset
+/// and abbreviation, and `DST` while daylight saving time is in effect.
+///
+/// ```text
+/// Asia/Tokyo     +09:00  JST
+/// Europe/London  +01:00  BST  DST
+/// ```
+pub(crate) fn render_zone_list(zones: &[DateTime<Zone>]) -> Vec<String> {
+    let names: Vec<String> = zones
+        .iter()
+        .map(|time| time.timezone().to_string())
+        .collect();
+    let width: usize = names.iter().map(String::len).max().unwrap_or(0);
+
+    zones
+        .iter()
+        .zip(names)
+        .map(|(time, name)| {
+            let line = format!(
+                "{:<width$}  {}  {}",
+                name,
+                time.format("%:z"),
+                time.offset().abbreviation().unwrap_or("-"),
+            );
+            if time.offset().is_dst() {
+                format!("{}  DST", line)
+            } else {
+                line
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn aligns_names_and_marks_dst() {
+        // Arrange
+        let now = Utc.with_ymd_and_hms(2024, 6, 27, 12, 0, 0).unwrap();
+        let zones = [
+            now.with_timezone(&Zone::Iana(chrono_tz::Asia::Tokyo)),
+            now.with_timezone(&Zone::Iana(chrono_tz::Europe::London)),
+        ];
+
+        // Act
+        let lines = render_zone_list(&zones);
+
+        // Assert
+        assert_eq!(
+            lines,
+            [
+                "Asia/Tokyo     +09:00  JST",
+                "Europe/London  +01:00  BST  DST",
+            ]
+        );
+    }
+}
@@ -0,0 +1,212 @@
+pub(crate) mod field_path;
+pub(crate) mod ndjson_error;
+
+use self::field_path::FieldPath;
+use self::ndjson_error::NdjsonError;
+use super::batch::on_error::OnError;
+use super::failure::Failure;
+use super::formatter::output_formatter::OutputFormatter;
+use super::render_text;
+use super::validator::command_options_validator::RequestOptions;
+use crate::domain::Clock;
+use serde_json::Value;
+use std::io::{BufRead, Write};
+use std::ops::ControlFlow;
+use std::process::ExitCode;
+
+/// Translates string fields of NDJSON documents (`--ndjson`), one
+/// document per line, keeping every other value and the field order.
+///
+/// Only one line is held at a time, so inputs of any size stream
+/// through. A field that is absent, or not a string, is left as it is;
+/// blank lines are copied. A line that is not JSON, or with a field
+/// that fails to translate, is handled as `on_error` says; failures are
+/// reported to `errors` with their line number.
+pub(crate) struct Ndjson<'a, C: Clock> {
+    pub(crate) options: &'a RequestOptions,
+    pub(crate) formatter: &'a OutputFormatter,
+    pub(crate) fields: &'a [FieldPath],
+    pub(crate) on_error: OnError,
+    pub(crate) clock: &'a C,
+}
+
+impl<C: Clock> Ndjson<'_, C> {
+    pub(crate) fn run(
+        &self,
+        input: impl BufRead,
+        mut output: impl Write,
+        mut errors: impl Write,
+    ) -> Result<ExitCode, NdjsonError> {
+        for (index, line) in input.lines().enumerate() {
+            let line: String = line?;
+            if line.trim().is_empty() {
+                writeln!(output, "{}", line)?;
+                continue;
+            }
+
+            match self.translate_document(&line, index + 1, &mut errors)? {
+                Ok(document) => writeln!(output, "{}", document)?,
+                Err(failure) => {
+                    let flow = self
+                        .on_error
+                        .handle(&failure, || writeln!(output, "{}", line))?;
+                    if let ControlFlow::Break(code) = flow {
+                        output.flush()?;
+                        return Ok(code);
+                    }
+                }
+            }
+        }
+
+        output.flush()?;
+        Ok(ExitCode::SUCCESS)
+    }
+
+    /// The document with its chosen fields translated, or the first
+    /// failure. Notices and failures are reported to `errors`, whose
+    /// own failure is the outer error.
+    fn translate_document(
+        &self,
+        line: &str,
+        line_number: usize,
+        errors: &mut impl Write,
+    ) -> Result<Result<Value, Failure>, NdjsonError> {
+        let mut document: Value = match serde_json::from_str(line) {
+            Ok(document) => document,
+            Err(e) => {
+                let failure = Failure::from(NdjsonError::from(e));
+                writeln!(errors, "line {}: {}", line_number, failure)?;
+                return Ok(Err(failure));
+            }
+        };
+
+        for path in self.fields {
+            let Some(Value::String(text)) = path.find_mut(&mut document) else {
+                continue;
+            };
+            match render_text(self.options, self.formatter, text.trim(), self.clock) {
+                Ok((mut lines, notice)) => {
+                    if let Some(notice) = notice {
+                        writeln!(errors, "line {}, field {}: {}", line_number, path, notice)?;
+                    }
+                    // one target and one occurrence, see `StreamOutput::InPlace`
+                    *text = lines.swap_remove(0);
+                }
+                Err(failure) => {
+                    writeln!(errors, "line {}, field {}: {}", line_number, path, failure)?;
+                    return Ok(Err(failure));
+                }
+            }
+        }
+
+        Ok(Ok(document))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presentation::stream_fixture::{capture, clock, request_options};
+
+    /// Fixture: runs NDJSON mode over `input` with the real CLI's
+    /// options, returning (exit code, stdout, stderr).
+    fn run_ndjson(
+        args: &[&str],
+        fields: &[&str],
+        on_error: OnError,
+        input: &str,
+    ) -> (ExitCode, String, String) {
+        let fields: Vec<FieldPath> = fields.iter().map(|path| path.parse().unwrap()).collect();
+        let ndjson = Ndjson {
+            options: &request_options(args),
+            formatter: &OutputFormatter::default(),
+            fields: &fields,
+            on_error,
+            clock: &clock(),
+        };
+        capture(|output, errors| ndjson.run(input.as_bytes(), output, errors).unwrap())
+    }
+
+    #[test]
+    fn translates_fields_keeping_order() {
+        // Arrange
+        let input = concat!(
+            r#"{"ts":"2024-01-01 12:00:00","level":"info","request":{"started_at":"2024-07-01T12:00:00Z","n":1.50}}"#,
+            "\n",
+        );
+
+        // Act
+        let (code, output, _) = run_ndjson(
+            &["-f", "UTC", "-t", "Asia/Tokyo"],
+            &[".ts", ".request.started_at"],
+            OnError::Fail,
+            input,
+        );
+
+        // Assert
+        assert_eq!(code, ExitCode::SUCCESS);
+        assert_eq!(
+            output,
+            concat!(
+                r#"{"ts":"2024-01-01 21:00:00 JST","level":"info","request":{"started_at":"2024-07-01 21:00:00 JST","n":1.50}}"#,
+                "\n",
+            )
+        );
+    }
+
+    #[test]
+    fn leaves_absent_and_non_string_fields() {
+        // Arrange
+        let input = "{\"ts\":1719491696}\n\n{\"other\":true}\n";
+
+        // Act
+        let (code, output, _) = run_ndjson(
+            &["-f", "UTC", "-t", "Asia/Tokyo"],
+            &[".ts"],
+            OnError::Fail,
+            input,
+        );
+
+        // Assert
+        assert_eq!(code, ExitCode::SUCCESS);
+        assert_eq!(output, input);
+    }
+
+    #[test]
+    fn copies_invalid_document_with_passthrough() {
+        // Arrange
+        let input = "not json\n{\"ts\":\"bad\"}\n";
+
+        // Act
+        let (code, output, errors) = run_ndjson(
+            &["-f", "UTC", "-t", "Asia/Tokyo"],
+            &[".ts"],
+            OnError::Passthrough,
+            input,
+        );
+
+        // Assert
+        assert_eq!(code, ExitCode::SUCCESS);
+        assert_eq!(output, input);
+        assert!(errors.starts_with("line 1: Validation Error: Invalid JSON document found."));
+        assert!(errors.contains("line 2, field .ts: Validation Error: Invalid time format found."));
+    }
+
+    #[test]
+    fn stops_at_failing_document_with_fail() {
+        // Arrange
+        let input = "{\"ts\":\"2024-01-01 12:00:00\"}\n{\"ts\":\"bad\"}\n";
+
+        // Act
+        let (code, output, _) = run_ndjson(
+            &["-f", "UTC", "-t", "Asia/Tokyo"],
+            &[".ts"],
+            OnError::Fail,
+            input,
+        );
+
+        // Assert
+        assert_eq!(code, ExitCode::from(3));
+        assert_eq!(output, "{\"ts\":\"2024-01-01 21:00:00 JST\"}\n");
+    }
+}
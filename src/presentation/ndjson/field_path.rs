@@ -0,0 +1,116 @@
+use serde_json::Value;
+use std::fmt;
+use std::str::FromStr;
+
+/// A path to a field inside a JSON document (`--field`), such as
+/// `.ts` or `.request.started_at`.
+///
+/// Each segment names an object key; a segment of digits indexes an
+/// array instead (`.events.0.ts`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FieldPath(Vec<String>);
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error("Validation Error: Invalid field path found. {0} (expected: .key or .key.nested, e.g. .request.started_at)")]
+pub(crate) struct FieldPathParseError(pub String);
+
+impl FromStr for FieldPath {
+    type Err = FieldPathParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let segments: Vec<String> = text
+            .strip_prefix('.')
+            .ok_or_else(|| FieldPathParseError(text.to_string()))?
+            .split('.')
+            .map(str::to_string)
+            .collect();
+        if segments.iter().any(String::is_empty) {
+            return Err(FieldPathParseError(text.to_string()));
+        }
+        Ok(FieldPath(segments))
+    }
+}
+
+impl fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for segment in &self.0 {
+            write!(f, ".{}", segment)?;
+        }
+        Ok(())
+    }
+}
+
+impl FieldPath {
+    /// The value at this path in `document`, if every segment exists.
+    pub(crate) fn find_mut<'a>(&self, document: &'a mut Value) -> Option<&'a mut Value> {
+        self.0
+            .iter()
+            .try_fold(document, |value, segment| match value {
+                Value::Object(object) => object.get_mut(segment),
+                Value::Array(array) => segment
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| array.get_mut(index)),
+                _ => None,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn finds_nested_field() {
+        // Arrange
+        let path: FieldPath = ".request.started_at".parse().unwrap();
+        let mut document = json!({"request": {"started_at": "2024-01-01 12:00:00"}});
+
+        // Act
+        let value = path.find_mut(&mut document);
+
+        // Assert
+        assert_eq!(value, Some(&mut json!("2024-01-01 12:00:00")));
+    }
+
+    #[test]
+    fn indexes_arrays_with_digits() {
+        // Arrange
+        let path: FieldPath = ".events.1.ts".parse().unwrap();
+        let mut document = json!({"events": [{"ts": "a"}, {"ts": "b"}]});
+
+        // Act
+        let value = path.find_mut(&mut document);
+
+        // Assert
+        assert_eq!(value, Some(&mut json!("b")));
+    }
+
+    #[test]
+    fn misses_absent_field() {
+        // Arrange
+        let path: FieldPath = ".ts.inner".parse().unwrap();
+        let mut document = json!({"ts": "2024-01-01 12:00:00"});
+
+        // Act
+        let value = path.find_mut(&mut document);
+
+        // Assert
+        assert_eq!(value, None);
+    }
+
+    #[test]
+    fn rejects_malformed_paths() {
+        // Arrange
+        let inputs = ["ts", ".", ".a..b", ".a."];
+
+        // Act & Assert
+        for input in inputs {
+            assert_eq!(
+                input.parse::<FieldPath>(),
+                Err(FieldPathParseError(input.to_string()))
+            );
+        }
+    }
+}
@@ -0,0 +1,11 @@
+/// Raised when NDJSON mode (`--ndjson`) meets a line that is not
+/// JSON, or cannot read or write the stream. Failures of single fields
+/// are ordinary `ValidationError`s and `TranslationError`s instead.
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum NdjsonError {
+    #[error("Validation Error: Invalid JSON document found. {0}")]
+    InvalidJson(#[from] serde_json::Error),
+
+    #[error("IO Error: {0}")]
+    Io(#[from] std::io::Error),
+}
@@ -0,0 +1,213 @@
+use super::batch::on_error::OnError;
+use super::failure::Failure;
+use super::formatter::output_formatter::OutputFormatter;
+use super::validator::command_options_validator::RequestOptions;
+use crate::domain::ConversionTime;
+use crate::usecase::TimezoneTranslator;
+use std::io::{self, BufRead, Write};
+use std::ops::ControlFlow;
+use std::process::ExitCode;
+
+/// Replaces every timestamp found in free-form lines (`--rewrite`)
+/// with its translation, leaving the rest of each line intact.
+///
+/// Lines are written as soon as they are read, so the mode works on
+/// an endless stream (`tail -f`). Lines that are not UTF-8 are copied
+/// unchanged. A line with a timestamp that fails to translate is
+/// handled as `on_error` says; failures are reported to `errors` with
+/// their line number.
+pub(crate) struct Rewrite<'a> {
+    pub(crate) options: &'a RequestOptions,
+    pub(crate) formatter: &'a OutputFormatter,
+    pub(crate) on_error: OnError,
+}
+
+impl Rewrite<'_> {
+    pub(crate) fn run(
+        &self,
+        mut input: impl BufRead,
+        mut output: impl Write,
+        mut errors: impl Write,
+    ) -> io::Result<ExitCode> {
+        let mut buffer: Vec<u8> = Vec::new();
+        let mut line_number: usize = 0;
+        loop {
+            buffer.clear();
+            if input.read_until(b'\n', &mut buffer)? == 0 {
+                break;
+            }
+            line_number += 1;
+            let Ok(line) = std::str::from_utf8(&buffer) else {
+                output.write_all(&buffer)?;
+                continue;
+            };
+
+            match self.rewrite_line(line) {
+                Ok((rewritten, notices)) => {
+                    for notice in notices {
+                        writeln!(errors, "line {}: {}", line_number, notice)?;
+                    }
+                    output.write_all(rewritten.as_bytes())?;
+                }
+                Err(failure) => {
+                    writeln!(errors, "line {}: {}", line_number, failure)?;
+                    let flow = self
+                        .on_error
+                        .handle(&failure, || output.write_all(&buffer))?;
+                    if let ControlFlow::Break(code) = flow {
+                        output.flush()?;
+                        return Ok(code);
+                    }
+                }
+            }
+            output.flush()?;
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+
+    /// The line with each timestamp replaced, and the notices of those
+    /// moved out of a DST gap; or the first failure.
+    fn rewrite_line(&self, line: &str) -> Result<(String, Vec<String>), Failure> {
+        let mut rewritten = String::with_capacity(line.len());
+        let mut notices: Vec<String> = Vec::new();
+        let mut copied: usize = 0;
+
+        for (range, time) in ConversionTime::scan(line) {
+            // an offset written in the log line wins over --from, which
+            // only stands for the timestamps that carry none
+            let request = self
+                .options
+                .request_with(time.into())
+                .with_explicit_source(false);
+            let translation = TimezoneTranslator::new(request).convert()?;
+            notices.extend(self.formatter.notice(&translation));
+            rewritten.push_str(&line[copied..range.start]);
+            // one target and one occurrence, see `StreamOutput::InPlace`
+            rewritten.push_str(&self.formatter.render(&translation)?[0]);
+            copied = range.end;
+        }
+        rewritten.push_str(&line[copied..]);
+
+        Ok((rewritten, notices))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presentation::stream_fixture::{capture, request_options};
+
+    /// Fixture: runs rewrite mode over `input` with the real CLI's
+    /// options, returning (exit code, stdout, stderr).
+    fn run_rewrite(args: &[&str], on_error: OnError, input: &str) -> (ExitCode, String, String) {
+        let rewrite = Rewrite {
+            options: &request_options(args),
+            formatter: &OutputFormatter::default(),
+            on_error,
+        };
+        capture(|output, errors| rewrite.run(input.as_bytes(), output, errors).unwrap())
+    }
+
+    #[test]
+    fn replaces_each_timestamp_in_place() {
+        // Arrange
+        let input = "INFO [2024-01-01 12:00:00] retry at 2024-01-01T12:05:00Z\r\nno time here\n";
+
+        // Act
+        let (code, output, _) =
+            run_rewrite(&["-f", "UTC", "-t", "Asia/Tokyo"], OnError::Fail, input);
+
+        // Assert
+        assert_eq!(code, ExitCode::SUCCESS);
+        assert_eq!(
+            output,
+            "INFO [2024-01-01 21:00:00 JST] retry at 2024-01-01 21:05:00 JST\r\nno time here\n"
+        );
+    }
+
+    #[test]
+    fn reads_embedded_offset_instead_of_source() {
+        // Arrange
+        let input = "a 2024-01-01T12:00:00+09:00 b 2024-01-01 12:00:00\n";
+
+        // Act
+        let (code, output, _) = run_rewrite(&["-f", "UTC", "-t", "UTC"], OnError::Fail, input);
+
+        // Assert
+        assert_eq!(code, ExitCode::SUCCESS);
+        assert_eq!(
+            output,
+            "a 2024-01-01 03:00:00 UTC b 2024-01-01 12:00:00 UTC\n"
+        );
+    }
+
+    #[test]
+    fn leaves_timestamps_with_trailing_fraction_alone() {
+        // Arrange
+        let input = "a 2024-01-01 12:00:00.1234567890 b\nc 2024-01-01 12:00:00,123 d\n";
+
+        // Act
+        let (code, output, _) =
+            run_rewrite(&["-f", "UTC", "-t", "Asia/Tokyo"], OnError::Fail, input);
+
+        // Assert
+        assert_eq!(code, ExitCode::SUCCESS);
+        assert_eq!(output, input);
+    }
+
+    #[test]
+    fn copies_non_utf8_line_unchanged() {
+        // Arrange
+        let input = b"\xff 2024-01-01 12:00:00\n";
+        let rewrite = Rewrite {
+            options: &request_options(&["-f", "UTC", "-t", "Asia/Tokyo"]),
+            formatter: &OutputFormatter::default(),
+            on_error: OnError::Fail,
+        };
+        let mut output: Vec<u8> = Vec::new();
+
+        // Act
+        let code = rewrite.run(&input[..], &mut output, io::sink()).unwrap();
+
+        // Assert
+        assert_eq!(code, ExitCode::SUCCESS);
+        assert_eq!(output, input);
+    }
+
+    #[test]
+    fn keeps_failing_line_with_passthrough() {
+        // Arrange
+        // 02:30 on 2024-03-10 does not exist in New York (DST gap)
+        let input = "a 2024-03-10 02:30:00\nb 2024-03-10 03:30:00\n";
+
+        // Act
+        let (code, output, errors) = run_rewrite(
+            &["-f", "America/New_York", "-t", "UTC"],
+            OnError::Passthrough,
+            input,
+        );
+
+        // Assert
+        assert_eq!(code, ExitCode::SUCCESS);
+        assert_eq!(output, "a 2024-03-10 02:30:00\nb 2024-03-10 07:30:00 UTC\n");
+        assert!(errors.starts_with("line 1: Translation Error"));
+    }
+
+    #[test]
+    fn stops_at_failing_line_with_fail() {
+        // Arrange
+        let input = "a 2024-03-10 01:30:00\nb 2024-03-10 02:30:00\nc\n";
+
+        // Act
+        let (code, output, _) = run_rewrite(
+            &["-f", "America/New_York", "-t", "UTC"],
+            OnError::Fail,
+            input,
+        );
+
+        // Assert
+        assert_eq!(code, ExitCode::from(50));
+        assert_eq!(output, "a 2024-03-10 06:30:00 UTC\n");
+    }
+}
@@ -0,0 +1,37 @@
+//! Fixtures shared by the binary's tests: the stream modes (batch,
+//! table, rewrite, NDJSON), the validators and the formatters.
+
+use super::command::command_definition::command_provider;
+use super::validator::command_options_validator::{validate_request_options, RequestOptions};
+use crate::domain::FixedClock;
+use chrono::{TimeZone, Utc};
+use clap::ArgMatches;
+
+/// Fixture: the real CLI's matches for `args`, read as a stream.
+pub(crate) fn stream_matches(args: &[&str]) -> ArgMatches {
+    command_provider().get_matches_from(["tzt", "--stdin"].into_iter().chain(args.iter().copied()))
+}
+
+/// Fixture: the request options the real CLI validates from `args`.
+pub(crate) fn request_options(args: &[&str]) -> RequestOptions {
+    validate_request_options(&stream_matches(args)).unwrap()
+}
+
+/// Fixture: "now" is 2024-06-27 12:00:00 UTC, the instant of the
+/// library's `FixedClock::fixture`, which is built for its own tests
+/// only.
+pub(crate) fn clock() -> FixedClock {
+    FixedClock(Utc.with_ymd_and_hms(2024, 6, 27, 12, 0, 0).unwrap())
+}
+
+/// Fixture: runs a mode with in-memory stdout and stderr, returning
+/// (its result, stdout, stderr).
+pub(crate) fn capture<T>(run: impl FnOnce(&mut Vec<u8>, &mut Vec<u8>) -> T) -> (T, String, String) {
+    let (mut output, mut errors) = (Vec::new(), Vec::new());
+    let result = run(&mut output, &mut errors);
+    (
+        result,
+        String::from_utf8(output).unwrap(),
+        String::from_utf8(errors).unwrap(),
+    )
+}
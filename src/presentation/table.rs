@@ -0,0 +1,286 @@
+pub(crate) mod column_selector;
+pub(crate) mod table_error;
+
+use self::column_selector::ColumnSelector;
+use self::table_error::TableError;
+use super::batch::on_error::OnError;
+use super::failure::Failure;
+use super::formatter::output_formatter::OutputFormatter;
+use super::render_text;
+use super::validator::command_options_validator::RequestOptions;
+use crate::domain::Clock;
+use csv::{ByteRecord, ReaderBuilder, WriterBuilder};
+use std::io::{Read, Write};
+use std::ops::ControlFlow;
+use std::process::ExitCode;
+
+/// Translates the chosen columns of a CSV or TSV table (`--csv`,
+/// `--tsv`), copying every other column untouched.
+///
+/// The first row is the header. Each chosen cell is replaced by its
+/// translation or, with `--append-column`, kept, and the translations
+/// are added as new columns at the end: one per chosen column and
+/// target timezone, titled `<column>_<timezone>`.
+///
+/// A row with a failing cell is handled as `on_error` says (`skip`
+/// drops the row, `passthrough` copies it unchanged); failures are
+/// reported to `errors` with their line and column.
+pub(crate) struct Table<'a, C: Clock> {
+    pub(crate) options: &'a RequestOptions,
+    pub(crate) formatter: &'a OutputFormatter,
+    pub(crate) columns: &'a [ColumnSelector],
+    pub(crate) delimiter: u8,
+    pub(crate) append: bool,
+    pub(crate) on_error: OnError,
+    pub(crate) clock: &'a C,
+}
+
+impl<C: Clock> Table<'_, C> {
+    pub(crate) fn run(
+        &self,
+        input: impl Read,
+        output: impl Write,
+        mut errors: impl Write,
+    ) -> Result<ExitCode, TableError> {
+        let mut reader = ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .flexible(true)
+            .from_reader(input);
+        let mut writer = WriterBuilder::new()
+            .delimiter(self.delimiter)
+            .flexible(true)
+            .from_writer(output);
+
+        let headers: ByteRecord = reader.byte_headers()?.clone();
+        let indexes: Vec<usize> = self
+            .columns
+            .iter()
+            .map(|column| {
+                column
+                    .find(&headers)
+                    .ok_or_else(|| TableError::UnknownColumn(column.as_str().to_string()))
+            })
+            .collect::<Result<_, _>>()?;
+        writer.write_byte_record(&self.header_row(&headers, &indexes))?;
+
+        for record in reader.byte_records() {
+            let record: ByteRecord = record?;
+            let line: u64 = record.position().map_or(0, |position| position.line());
+            let mut rendered: Vec<Vec<String>> = Vec::new();
+            let mut failure: Option<Failure> = None;
+
+            for &index in &indexes {
+                let cell = String::from_utf8_lossy(record.get(index).unwrap_or_default());
+                let column = String::from_utf8_lossy(&headers[index]);
+                match render_text(self.options, self.formatter, cell.trim(), self.clock) {
+                    Ok((lines, notice)) => {
+                        if let Some(notice) = notice {
+                            writeln!(errors, "line {}, column {}: {}", line, column, notice)?;
+                        }
+                        rendered.push(lines);
+                    }
+                    Err(cell_failure) => {
+                        writeln!(errors, "line {}, column {}: {}", line, column, cell_failure)?;
+                        failure.get_or_insert(cell_failure);
+                    }
+                }
+            }
+
+            let Some(failure) = failure else {
+                writer.write_byte_record(&self.translated(record, &indexes, rendered))?;
+                continue;
+            };
+            let flow = self
+                .on_error
+                .handle(&failure, || writer.write_byte_record(&self.padded(record)))?;
+            if let ControlFlow::Break(code) = flow {
+                writer.flush()?;
+                return Ok(code);
+            }
+        }
+
+        writer.flush()?;
+        Ok(ExitCode::SUCCESS)
+    }
+
+    fn header_row(&self, headers: &ByteRecord, indexes: &[usize]) -> ByteRecord {
+        let mut row: ByteRecord = headers.clone();
+        if self.append {
+            for &index in indexes {
+                for target in self.options.targets() {
+                    let mut title: Vec<u8> = headers[index].to_vec();
+                    title.push(b'_');
+                    title.extend_from_slice(target.timezone().to_string().as_bytes());
+                    row.push_field(&title);
+                }
+            }
+        }
+        row
+    }
+
+    /// The row with the rendered translations of its chosen cells
+    /// (`rendered` holds one entry per chosen column, one string per
+    /// target), in place or appended.
+    fn translated(
+        &self,
+        mut record: ByteRecord,
+        indexes: &[usize],
+        rendered: Vec<Vec<String>>,
+    ) -> ByteRecord {
+        if self.append {
+            for text in rendered.iter().flatten() {
+                record.push_field(text.as_bytes());
+            }
+            return record;
+        }
+
+        let mut row = ByteRecord::new();
+        for (position, field) in record.iter().enumerate() {
+            match indexes.iter().position(|&index| index == position) {
+                Some(chosen) => row.push_field(rendered[chosen][0].as_bytes()),
+                None => row.push_field(field),
+            }
+        }
+        row
+    }
+
+    /// The row unchanged; with `--append-column`, followed by empty
+    /// cells where its translations would be.
+    fn padded(&self, mut record: ByteRecord) -> ByteRecord {
+        if self.append {
+            for _ in 0..self.columns.len() * self.options.targets().len() {
+                record.push_field(b"");
+            }
+        }
+        record
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presentation::stream_fixture::{capture, clock, request_options};
+
+    /// Fixture: runs table mode over a comma-separated `input` with the
+    /// real CLI's options, returning (exit code, stdout, stderr).
+    fn run_table(
+        args: &[&str],
+        columns: &[&str],
+        append: bool,
+        on_error: OnError,
+        input: &str,
+    ) -> (Result<ExitCode, TableError>, String, String) {
+        let columns: Vec<ColumnSelector> = columns.iter().map(|&c| c.into()).collect();
+        let table = Table {
+            options: &request_options(args),
+            formatter: &OutputFormatter::default(),
+            columns: &columns,
+            delimiter: b',',
+            append,
+            on_error,
+            clock: &clock(),
+        };
+        capture(|output, errors| table.run(input.as_bytes(), output, errors))
+    }
+
+    const BILLING: &str =
+        "id,created_at,amount\n1,2024-01-01 12:00:00,100\n2,2024-07-01 12:00:00,200\n";
+
+    #[test]
+    fn replaces_chosen_column() {
+        // Arrange
+        let args = ["-f", "UTC", "-t", "Asia/Tokyo"];
+
+        // Act
+        let (result, output, _) = run_table(&args, &["created_at"], false, OnError::Fail, BILLING);
+
+        // Assert
+        assert_eq!(result.unwrap(), ExitCode::SUCCESS);
+        assert_eq!(
+            output,
+            "id,created_at,amount\n1,2024-01-01 21:00:00 JST,100\n2,2024-07-01 21:00:00 JST,200\n"
+        );
+    }
+
+    #[test]
+    fn appends_one_column_per_target() {
+        // Arrange
+        let args = ["-f", "UTC", "-t", "Asia/Tokyo,Europe/London"];
+        // the second column, chosen by position
+        let columns = ["2"];
+
+        // Act
+        let (result, output, _) = run_table(&args, &columns, true, OnError::Fail, BILLING);
+
+        // Assert
+        assert_eq!(result.unwrap(), ExitCode::SUCCESS);
+        assert_eq!(
+            output.lines().collect::<Vec<_>>(),
+            [
+                "id,created_at,amount,created_at_Asia/Tokyo,created_at_Europe/London",
+                "1,2024-01-01 12:00:00,100,2024-01-01 21:00:00 JST,2024-01-01 12:00:00 GMT",
+                "2,2024-07-01 12:00:00,200,2024-07-01 21:00:00 JST,2024-07-01 13:00:00 BST",
+            ]
+        );
+    }
+
+    #[test]
+    fn copies_failing_row_with_passthrough() {
+        // Arrange
+        let input = "id,created_at\n1,bad\n2,2024-07-01 12:00:00\n";
+
+        // Act
+        let (result, output, errors) = run_table(
+            &["-f", "UTC", "-t", "Asia/Tokyo"],
+            &["created_at"],
+            true,
+            OnError::Passthrough,
+            input,
+        );
+
+        // Assert
+        assert_eq!(result.unwrap(), ExitCode::SUCCESS);
+        assert_eq!(
+            output,
+            "id,created_at,created_at_Asia/Tokyo\n1,bad,\n2,2024-07-01 12:00:00,2024-07-01 21:00:00 JST\n"
+        );
+        assert!(errors.starts_with("line 2, column created_at: Validation Error"));
+    }
+
+    #[test]
+    fn stops_at_failing_row_with_fail() {
+        // Arrange
+        let input = "id,created_at\n1,2024-01-01 12:00:00\n2,bad\n";
+
+        // Act
+        let (result, output, _) = run_table(
+            &["-f", "UTC", "-t", "Asia/Tokyo"],
+            &["created_at"],
+            false,
+            OnError::Fail,
+            input,
+        );
+
+        // Assert
+        assert_eq!(result.unwrap(), ExitCode::from(3));
+        assert_eq!(output, "id,created_at\n1,2024-01-01 21:00:00 JST\n");
+    }
+
+    #[test]
+    fn rejects_unknown_column() {
+        // Arrange
+        let columns = ["updated_at"];
+
+        // Act
+        let (result, _, _) = run_table(
+            &["-f", "UTC", "-t", "Asia/Tokyo"],
+            &columns,
+            false,
+            OnError::Fail,
+            BILLING,
+        );
+
+        // Assert
+        assert!(matches!(result, Err(TableError::UnknownColumn(name)) if name == "updated_at"));
+    }
+}
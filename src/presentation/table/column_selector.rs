@@ -0,0 +1,80 @@
+use csv::ByteRecord;
+
+/// A column picked with `--column`: a header name, or a 1-based
+/// position when no header carries that name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ColumnSelector(String);
+
+impl From<&str> for ColumnSelector {
+    fn from(text: &str) -> Self {
+        ColumnSelector(text.trim().to_string())
+    }
+}
+
+impl ColumnSelector {
+    /// The 0-based index of the column in `headers`, if it exists.
+    ///
+    /// A header name wins over a position, so a column titled `2` is
+    /// found by its name.
+    pub(crate) fn find(&self, headers: &ByteRecord) -> Option<usize> {
+        headers
+            .iter()
+            .position(|header| header == self.0.as_bytes())
+            .or_else(|| {
+                self.0
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|position| (1..=headers.len()).contains(position))
+                    .map(|position| position - 1)
+            })
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers() -> ByteRecord {
+        ByteRecord::from(vec!["id", "created_at", "2"])
+    }
+
+    #[test]
+    fn finds_column_by_name_or_position() {
+        // Arrange
+        let by_name = ColumnSelector::from("created_at");
+        let by_position = ColumnSelector::from("1");
+
+        // Act & Assert
+        assert_eq!(by_name.find(&headers()), Some(1));
+        assert_eq!(by_position.find(&headers()), Some(0));
+    }
+
+    #[test]
+    fn prefers_header_name_over_position() {
+        // Arrange
+        let selector = ColumnSelector::from("2");
+
+        // Act
+        let index = selector.find(&headers());
+
+        // Assert
+        assert_eq!(index, Some(2));
+    }
+
+    #[test]
+    fn misses_unknown_column() {
+        // Arrange
+        let unknown_name = ColumnSelector::from("updated_at");
+        let out_of_range = ColumnSelector::from("4");
+        let zero = ColumnSelector::from("0");
+
+        // Act & Assert
+        assert_eq!(unknown_name.find(&headers()), None);
+        assert_eq!(out_of_range.find(&headers()), None);
+        assert_eq!(zero.find(&headers()), None);
+    }
+}
@@ -0,0 +1,22 @@
+/// Raised when table mode (`--csv`, `--tsv`) cannot find a column or
+/// cannot read or write the table. Failures of single cells are ordinary
+/// `ValidationError`s and `TranslationError`s instead.
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum TableError {
+    #[error(
+        "Validation Error: Column not found. {0} (expected a header name or a 1-based position)"
+    )]
+    UnknownColumn(String),
+
+    #[error("IO Error: {0}")]
+    Csv(#[from] csv::Error),
+
+    #[error("IO Error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("IO Error: {path}: {source}")]
+    Open {
+        path: String,
+        source: std::io::Error,
+    },
+}
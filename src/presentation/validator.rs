@@ -1,2 +1,6 @@
 pub(crate) mod command_options_validator;
-mod validation_error;
+pub(crate) mod info_options_validator;
+pub(crate) mod list_options_validator;
+pub(crate) mod output_options_validator;
+pub(crate) mod stream_options_validator;
+pub(crate) mod validation_error;
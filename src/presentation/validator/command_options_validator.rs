@@ -1,40 +1,194 @@
 use super::validation_error::ValidationError;
 use crate::domain::{
-    AmbiguousTimeStrategy, ConversionTime, SourceTimezone, TargetTimezone, TranslationRequest,
+    AmbiguousTimeStrategy, Clock, ConversionTime, EpochTime, EpochUnit, GapTimeStrategy,
+    InputFormat, PreferredRegion, RelativeTime, RelativeTimeParseError, RequestedTime,
+    SourceTimezone, TargetTimezone, TranslationRequest, ZoneParseOptions,
 };
+use clap::parser::ValueSource;
 use clap::ArgMatches;
 
 /// Parses raw CLI strings into a validated `TranslationRequest`.
 ///
-/// The `unwrap()` calls are safe: clap guarantees `time` (required)
-/// and the other options (defaulted) are always present.
+/// The `unwrap()` calls are safe: clap guarantees `time` and the other
+/// options (all defaulted) are always present.
 pub(crate) fn validate_command_options(
     arg: &ArgMatches,
+    clock: &impl Clock,
 ) -> Result<TranslationRequest, ValidationError> {
-    let time: ConversionTime = arg.get_one::<String>("time").unwrap().parse()?;
-    let source: SourceTimezone = arg.get_one::<String>("from_timezone").unwrap().parse()?;
-    let target: TargetTimezone = arg.get_one::<String>("to_timezone").unwrap().parse()?;
+    validate_request_options(arg)?.request_for(arg.get_one::<String>("time").unwrap(), clock)
+}
+
+/// Every input of a `TranslationRequest` except the time itself,
+/// parsed once so that batch, table and rewrite modes can pair it
+/// with each line, cell or timestamp read.
+#[derive(Debug, Clone)]
+pub(crate) struct RequestOptions {
+    source: SourceTimezone,
+    targets: Vec<TargetTimezone>,
+    strategy: AmbiguousTimeStrategy,
+    gap_strategy: GapTimeStrategy,
+    source_is_explicit: bool,
+    time_unit: Option<EpochUnit>,
+    input_formats: Vec<InputFormat>,
+}
+
+/// Parses the CLI strings other than `--time` into `RequestOptions`.
+///
+/// `--to` may be repeated or hold comma-separated timezones; their
+/// order is kept.
+///
+/// `--from` counts as explicit only when typed on the command line;
+/// the local-timezone default must not contradict an offset in `--time`.
+pub(crate) fn validate_request_options(
+    arg: &ArgMatches,
+) -> Result<RequestOptions, ValidationError> {
+    let zone_options: ZoneParseOptions = validate_zone_parse_options(arg)?;
+    let source = SourceTimezone::parse_with(
+        arg.get_one::<String>("from_timezone").unwrap(),
+        &zone_options,
+    )?;
+    let time_unit: Option<EpochUnit> = arg
+        .get_one::<String>("time_unit")
+        .map(|text| text.parse())
+        .transpose()?;
+    let input_formats: Vec<InputFormat> = arg
+        .get_many::<String>("input_format")
+        .unwrap_or_default()
+        .map(|text| text.parse())
+        .collect::<Result<_, _>>()?;
+    let targets: Vec<TargetTimezone> = arg
+        .get_many::<String>("to_timezone")
+        .unwrap()
+        .flat_map(|text| text.split(','))
+        .map(|text| TargetTimezone::parse_with(text.trim(), &zone_options))
+        .collect::<Result<_, _>>()?;
     let strategy: AmbiguousTimeStrategy = arg
         .get_one::<String>("ambiguous_time_strategy")
         .unwrap()
         .parse()?;
+    let gap_strategy: GapTimeStrategy = arg.get_one::<String>("gap_strategy").unwrap().parse()?;
+
+    let source_is_explicit: bool =
+        arg.value_source("from_timezone") == Some(ValueSource::CommandLine);
+
+    Ok(RequestOptions {
+        source,
+        targets,
+        strategy,
+        gap_strategy,
+        source_is_explicit,
+        time_unit,
+        input_formats,
+    })
+}
+
+/// Reads how timezone names are matched: `--prefer-region` and
+/// `--strict`, shared by the translation and `tzt info`.
+pub(crate) fn validate_zone_parse_options(
+    arg: &ArgMatches,
+) -> Result<ZoneParseOptions, ValidationError> {
+    let preferred_region: Option<PreferredRegion> = arg
+        .get_one::<String>("prefer_region")
+        .map(|text| text.parse())
+        .transpose()?;
+    Ok(ZoneParseOptions::default()
+        .with_preferred_region(preferred_region)
+        .with_strict(arg.get_flag("strict")))
+}
+
+impl RequestOptions {
+    /// The target timezones, in the order they were given.
+    pub(crate) fn targets(&self) -> &[TargetTimezone] {
+        &self.targets
+    }
+
+    pub(crate) fn strategy(&self) -> AmbiguousTimeStrategy {
+        self.strategy
+    }
+
+    /// Builds the request for `time_text`.
+    ///
+    /// The text is read as an epoch value when `--time-unit` is given or
+    /// it starts with `@`, as a relative expression (`now + 3h`) when it
+    /// starts with a relative keyword, and as a wall-clock time otherwise.
+    /// Relative expressions are resolved against `clock` in the source
+    /// timezone.
+    pub(crate) fn request_for(
+        &self,
+        time_text: &str,
+        clock: &impl Clock,
+    ) -> Result<TranslationRequest, ValidationError> {
+        let time: RequestedTime = match self.time_unit {
+            Some(unit) => EpochTime::parse(time_text, unit)?.into(),
+            None if time_text.starts_with('@') => time_text.parse::<EpochTime>()?.into(),
+            None if RelativeTime::looks_relative(time_text) => time_text
+                .parse::<RelativeTime>()?
+                .resolve(clock, self.source.timezone())
+                .ok_or_else(|| RelativeTimeParseError(time_text.to_string()))?,
+            None => ConversionTime::parse_with_formats(time_text, &self.input_formats)?.into(),
+        };
+
+        Ok(self.request_with(time))
+    }
+
+    /// Builds the request for a time that is already parsed (e.g. one
+    /// found inside a log line).
+    pub(crate) fn request_with(&self, time: RequestedTime) -> TranslationRequest {
+        let mut targets = self.targets.iter().copied();
+        // clap guarantees at least one value, and splitting never yields zero
+        let target: TargetTimezone = targets.next().unwrap();
 
-    Ok(TranslationRequest::new(time, source, target, strategy))
+        TranslationRequest::builder(time, self.source, target)
+            .targets(targets)
+            .ambiguous_time_strategy(self.strategy)
+            .gap_strategy(self.gap_strategy)
+            .explicit_source(self.source_is_explicit)
+            .build()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::Zone;
+    use crate::presentation::stream_fixture::clock;
+    use chrono::{Offset, TimeZone};
     use clap::{Arg, Command};
 
     /// Fixture: builds `ArgMatches` the same shape as the real CLI.
     fn arg_matches(time: &str, from_tz: &str, to_tz: &str) -> ArgMatches {
+        arg_matches_with(time, from_tz, to_tz, &[])
+    }
+
+    /// Fixture: same as `arg_matches`, plus optional flags.
+    fn arg_matches_with(time: &str, from_tz: &str, to_tz: &str, flags: &[&str]) -> ArgMatches {
         Command::new("test")
             .arg(Arg::new("time").required(true))
             .arg(Arg::new("from_timezone").required(true))
             .arg(Arg::new("to_timezone").required(true))
             .arg(Arg::new("ambiguous_time_strategy").default_value("earliest"))
-            .get_matches_from(vec!["test", time, from_tz, to_tz])
+            .arg(
+                Arg::new("gap_strategy")
+                    .long("gap-strategy")
+                    .default_value("error"),
+            )
+            .arg(Arg::new("time_unit").long("time-unit"))
+            .arg(Arg::new("prefer_region").long("prefer-region"))
+            .arg(
+                Arg::new("strict")
+                    .long("strict")
+                    .action(clap::ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new("input_format")
+                    .long("input-format")
+                    .action(clap::ArgAction::Append),
+            )
+            .get_matches_from(
+                ["test", time, from_tz, to_tz]
+                    .into_iter()
+                    .chain(flags.iter().copied()),
+            )
     }
 
     #[test]
@@ -43,11 +197,14 @@ mod tests {
         let matches = arg_matches("2024-06-27 12:34:56", "America/New_York", "Europe/London");
 
         // Act
-        let request = validate_command_options(&matches).unwrap();
+        let request = validate_command_options(&matches, &clock()).unwrap();
 
         // Assert
         let expected = TranslationRequest::new(
-            "2024-06-27 12:34:56".parse().unwrap(),
+            "2024-06-27 12:34:56"
+                .parse::<ConversionTime>()
+                .unwrap()
+                .into(),
             "America/New_York".parse().unwrap(),
             "Europe/London".parse().unwrap(),
             AmbiguousTimeStrategy::Earliest,
@@ -61,7 +218,7 @@ mod tests {
         let matches = arg_matches("invalid-time", "America/New_York", "Europe/London");
 
         // Act
-        let result = validate_command_options(&matches);
+        let result = validate_command_options(&matches, &clock());
 
         // Assert
         assert!(matches!(result, Err(ValidationError::Time(_))));
@@ -73,7 +230,7 @@ mod tests {
         let matches = arg_matches("2024-06-27 12:34:56", "Invalid/Timezone", "Europe/London");
 
         // Act
-        let result = validate_command_options(&matches);
+        let result = validate_command_options(&matches, &clock());
 
         // Assert
         assert!(matches!(result, Err(ValidationError::Timezone(_))));
@@ -89,9 +246,209 @@ mod tests {
         );
 
         // Act
-        let result = validate_command_options(&matches);
+        let result = validate_command_options(&matches, &clock());
+
+        // Assert
+        assert!(matches!(result, Err(ValidationError::Timezone(_))));
+    }
+
+    #[test]
+    fn reads_at_prefixed_time_as_epoch_seconds() {
+        // Arrange
+        let matches = arg_matches("@1719491696", "UTC", "Asia/Tokyo");
+
+        // Act
+        let request = validate_command_options(&matches, &clock()).unwrap();
+
+        // Assert
+        assert_eq!(
+            request.time(),
+            RequestedTime::Epoch("@1719491696".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn reads_time_as_epoch_in_given_unit() {
+        // Arrange
+        let matches =
+            arg_matches_with("1719491696000", "UTC", "Asia/Tokyo", &["--time-unit", "ms"]);
+
+        // Act
+        let request = validate_command_options(&matches, &clock()).unwrap();
+
+        // Assert
+        assert_eq!(
+            request.time(),
+            RequestedTime::Epoch("@1719491696".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_time_unit() {
+        // Arrange
+        let matches = arg_matches_with("1719491696", "UTC", "Asia/Tokyo", &["--time-unit", "h"]);
+
+        // Act
+        let result = validate_command_options(&matches, &clock());
+
+        // Assert
+        assert!(matches!(result, Err(ValidationError::EpochUnit(_))));
+    }
+
+    #[test]
+    fn resolves_relative_time_in_source_timezone() {
+        // Arrange
+        // it is already 2024-06-27 21:00 in Tokyo, so tomorrow is the 28th
+        let matches = arg_matches("tomorrow 09:00", "Asia/Tokyo", "UTC");
+
+        // Act
+        let request = validate_command_options(&matches, &clock()).unwrap();
+
+        // Assert
+        assert_eq!(
+            request.time(),
+            RequestedTime::WallClock("2024-06-28 09:00:00".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_relative_time() {
+        // Arrange
+        let matches = arg_matches("tomorrow 9am", "Asia/Tokyo", "UTC");
+
+        // Act
+        let result = validate_command_options(&matches, &clock());
+
+        // Assert
+        assert!(matches!(result, Err(ValidationError::RelativeTime(_))));
+    }
+
+    #[test]
+    fn reads_time_with_repeated_input_formats() {
+        // Arrange
+        let matches = arg_matches_with(
+            "20240627T123456",
+            "UTC",
+            "Asia/Tokyo",
+            &[
+                "--input-format",
+                "%d/%m/%Y %H:%M",
+                "--input-format",
+                "%Y%m%dT%H%M%S",
+            ],
+        );
+
+        // Act
+        let request = validate_command_options(&matches, &clock()).unwrap();
+
+        // Assert
+        assert_eq!(
+            request.time(),
+            RequestedTime::WallClock("2024-06-27 12:34:56".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_input_format() {
+        // Arrange
+        let matches = arg_matches_with(
+            "2024-06-27 12:34:56",
+            "UTC",
+            "Asia/Tokyo",
+            &["--input-format", "%Q"],
+        );
+
+        // Act
+        let result = validate_command_options(&matches, &clock());
+
+        // Assert
+        assert!(matches!(result, Err(ValidationError::InputFormat(_))));
+    }
+
+    #[test]
+    fn settles_ambiguous_abbreviation_with_preferred_region() {
+        // Arrange
+        let matches = arg_matches_with(
+            "2024-06-27 12:34:56",
+            "IST",
+            "UTC",
+            &["--prefer-region", "Europe"],
+        );
+
+        // Act
+        let request = validate_command_options(&matches, &clock()).unwrap();
+
+        // Assert
+        // Irish Standard Time, UTC+01:00
+        let dublin_noon = request
+            .source_timezone()
+            .with_ymd_and_hms(2024, 6, 27, 12, 0, 0)
+            .unwrap();
+        assert_eq!(dublin_noon.offset().fix().local_minus_utc(), 3600);
+    }
+
+    #[test]
+    fn rejects_invalid_preferred_region() {
+        // Arrange
+        let matches = arg_matches_with(
+            "2024-06-27 12:34:56",
+            "IST",
+            "UTC",
+            &["--prefer-region", "Atlantis"],
+        );
+
+        // Act
+        let result = validate_command_options(&matches, &clock());
+
+        // Assert
+        assert!(matches!(result, Err(ValidationError::PreferredRegion(_))));
+    }
+
+    #[test]
+    fn requires_exact_timezone_name_with_strict_flag() {
+        // Arrange
+        let matches = arg_matches_with("2024-06-27 12:34:56", "asia/tokyo", "UTC", &["--strict"]);
+
+        // Act
+        let result = validate_command_options(&matches, &clock());
 
         // Assert
         assert!(matches!(result, Err(ValidationError::Timezone(_))));
     }
+
+    #[test]
+    fn splits_comma_separated_target_timezones() {
+        // Arrange
+        let matches = arg_matches("2024-06-27 12:34:56", "UTC", "Asia/Tokyo, Europe/London");
+
+        // Act
+        let request = validate_command_options(&matches, &clock()).unwrap();
+
+        // Assert
+        let targets: Vec<Zone> = request.target_timezones().collect();
+        assert_eq!(
+            targets,
+            vec![
+                Zone::Iana(chrono_tz::Asia::Tokyo),
+                Zone::Iana(chrono_tz::Europe::London)
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_gap_strategy() {
+        // Arrange
+        let matches = arg_matches_with(
+            "2024-03-10 02:30:00",
+            "America/New_York",
+            "UTC",
+            &["--gap-strategy", "shift"],
+        );
+
+        // Act
+        let result = validate_command_options(&matches, &clock());
+
+        // Assert
+        assert!(matches!(result, Err(ValidationError::GapTimeStrategy(_))));
+    }
 }
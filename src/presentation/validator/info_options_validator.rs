@@ -0,0 +1,54 @@
+use super::command_options_validator::validate_zone_parse_options;
+use super::validation_error::ValidationError;
+use crate::domain::SourceTimezone;
+use clap::ArgMatches;
+
+/// Parses the zone of `tzt info`, matched like `--from`.
+///
+/// The `unwrap()` is safe: clap requires the zone.
+pub(crate) fn validate_info_options(arg: &ArgMatches) -> Result<SourceTimezone, ValidationError> {
+    Ok(SourceTimezone::parse_with(
+        arg.get_one::<String>("zone").unwrap(),
+        &validate_zone_parse_options(arg)?,
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Zone;
+    use clap::{Arg, ArgAction, Command};
+
+    /// Fixture: builds `ArgMatches` with the options of `tzt info`.
+    fn arg_matches(args: &[&str]) -> ArgMatches {
+        Command::new("test")
+            .arg(Arg::new("zone").required(true))
+            .arg(Arg::new("prefer_region").long("prefer-region"))
+            .arg(Arg::new("strict").long("strict").action(ArgAction::SetTrue))
+            .get_matches_from(std::iter::once("test").chain(args.iter().copied()))
+    }
+
+    #[test]
+    fn matches_zone_like_from() {
+        // Arrange
+        let matches = arg_matches(&["london"]);
+
+        // Act
+        let zone = validate_info_options(&matches).unwrap();
+
+        // Assert
+        assert_eq!(zone.timezone(), Zone::Iana(chrono_tz::Europe::London));
+    }
+
+    #[test]
+    fn rejects_inexact_name_when_strict() {
+        // Arrange
+        let matches = arg_matches(&["europe/london", "--strict"]);
+
+        // Act
+        let result = validate_info_options(&matches);
+
+        // Assert
+        assert!(matches!(result, Err(ValidationError::Timezone(_))));
+    }
+}
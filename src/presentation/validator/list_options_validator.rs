@@ -0,0 +1,93 @@
+use super::validation_error::ValidationError;
+use crate::domain::{ListOrder, ListRequest, PreferredRegion, UtcOffset};
+use clap::ArgMatches;
+
+/// Parses the CLI strings of `tzt list` into a `ListRequest`.
+///
+/// Every filter is optional; `--sort` is defaulted by clap.
+pub(crate) fn validate_list_options(arg: &ArgMatches) -> Result<ListRequest, ValidationError> {
+    let region: Option<PreferredRegion> = arg
+        .get_one::<String>("region")
+        .map(|text| text.parse())
+        .transpose()?;
+    let offset: Option<UtcOffset> = arg
+        .get_one::<String>("offset")
+        .map(|text| text.parse())
+        .transpose()?;
+    let order: ListOrder = arg.get_one::<String>("sort").unwrap().parse()?;
+
+    Ok(ListRequest::default()
+        .with_pattern(arg.get_one::<String>("pattern").cloned())
+        .with_region(region)
+        .with_offset(offset)
+        .with_order(order))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{ListOrderParseError, UtcOffsetParseError};
+    use clap::{Arg, Command};
+
+    /// Fixture: builds `ArgMatches` with the options of `tzt list`.
+    fn arg_matches(args: &[&str]) -> ArgMatches {
+        Command::new("test")
+            .arg(Arg::new("pattern"))
+            .arg(Arg::new("region").long("region"))
+            .arg(Arg::new("offset").long("offset").allow_hyphen_values(true))
+            .arg(Arg::new("sort").long("sort").default_value("name"))
+            .get_matches_from(std::iter::once("test").chain(args.iter().copied()))
+    }
+
+    #[test]
+    fn builds_request_from_filters() {
+        // Arrange
+        let matches = arg_matches(&["tokyo", "--region", "asia", "--offset", "+09:00"]);
+
+        // Act
+        let request = validate_list_options(&matches).unwrap();
+
+        // Assert
+        assert_eq!(
+            request,
+            ListRequest::default()
+                .with_pattern(Some("tokyo".to_string()))
+                .with_region(Some("Asia".parse().unwrap()))
+                .with_offset(Some("+09:00".parse().unwrap()))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_offset() {
+        // Arrange
+        let matches = arg_matches(&["--offset", "-25:00"]);
+
+        // Act
+        let result = validate_list_options(&matches);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(ValidationError::UtcOffset(UtcOffsetParseError(
+                "-25:00".to_string()
+            )))
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_sort_order() {
+        // Arrange
+        let matches = arg_matches(&["--sort", "size"]);
+
+        // Act
+        let result = validate_list_options(&matches);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(ValidationError::ListOrder(ListOrderParseError(
+                "size".to_string()
+            )))
+        );
+    }
+}
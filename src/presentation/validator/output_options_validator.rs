@@ -0,0 +1,146 @@
+use super::validation_error::ValidationError;
+use crate::domain::{EpochUnit, OutputFormat, OutputPrecision};
+use crate::presentation::formatter::output_formatter::{OutputFormatter, OutputLayout};
+use crate::presentation::formatter::output_preset::OutputPreset;
+use clap::ArgMatches;
+
+/// Parses the output-related CLI strings into an `OutputFormatter`.
+///
+/// Every output option is optional; an absent option keeps the
+/// default rendering. clap lets at most one of `--epoch-output`,
+/// `--format`, `--output` and `--json` through.
+pub(crate) fn validate_output_options(
+    arg: &ArgMatches,
+) -> Result<OutputFormatter, ValidationError> {
+    let precision: Option<OutputPrecision> = arg
+        .get_one::<String>("precision")
+        .map(|text| text.parse())
+        .transpose()?;
+    let epoch_unit: Option<EpochUnit> = arg
+        .get_one::<String>("epoch_output")
+        .map(|text| text.parse())
+        .transpose()?;
+
+    let format: Option<OutputFormat> = arg
+        .get_one::<String>("format")
+        .map(|text| text.parse())
+        .transpose()?;
+    let preset: Option<OutputPreset> = arg
+        .get_one::<String>("output")
+        .map(|text| text.parse())
+        .transpose()?;
+
+    let layout = match (epoch_unit, format, preset) {
+        _ if arg.get_flag("json") => OutputLayout::Json,
+        (Some(unit), _, _) => OutputLayout::Epoch(unit),
+        (_, Some(format), _) => OutputLayout::Pattern(format),
+        (_, _, Some(preset)) => OutputLayout::Preset(preset),
+        (None, None, None) => OutputLayout::Display,
+    };
+
+    Ok(OutputFormatter::new(precision, layout))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::{Arg, ArgAction, Command};
+
+    /// Fixture: builds `ArgMatches` with the output options of the real CLI.
+    fn arg_matches(args: &[&str]) -> ArgMatches {
+        Command::new("test")
+            .arg(Arg::new("precision").long("precision"))
+            .arg(Arg::new("epoch_output").long("epoch-output"))
+            .arg(Arg::new("format").long("format"))
+            .arg(Arg::new("output").long("output"))
+            .arg(Arg::new("json").long("json").action(ArgAction::SetTrue))
+            .get_matches_from(std::iter::once("test").chain(args.iter().copied()))
+    }
+
+    #[test]
+    fn builds_default_formatter_without_options() {
+        // Arrange
+        let matches = arg_matches(&[]);
+
+        // Act
+        let formatter = validate_output_options(&matches).unwrap();
+
+        // Assert
+        assert_eq!(formatter, OutputFormatter::default());
+    }
+
+    #[test]
+    fn rejects_invalid_precision() {
+        // Arrange
+        let matches = arg_matches(&["--precision", "12"]);
+
+        // Act
+        let result = validate_output_options(&matches);
+
+        // Assert
+        assert!(matches!(result, Err(ValidationError::OutputPrecision(_))));
+    }
+
+    #[test]
+    fn rejects_invalid_epoch_output_unit() {
+        // Arrange
+        let matches = arg_matches(&["--epoch-output", "days"]);
+
+        // Act
+        let result = validate_output_options(&matches);
+
+        // Assert
+        assert!(matches!(result, Err(ValidationError::EpochUnit(_))));
+    }
+
+    #[test]
+    fn rejects_invalid_output_format() {
+        // Arrange
+        let matches = arg_matches(&["--format", "%Y-%Q"]);
+
+        // Act
+        let result = validate_output_options(&matches);
+
+        // Assert
+        assert!(matches!(result, Err(ValidationError::OutputFormat(_))));
+    }
+
+    #[test]
+    fn builds_formatter_with_output_preset() {
+        // Arrange
+        let matches = arg_matches(&["--output", "sql"]);
+
+        // Act
+        let formatter = validate_output_options(&matches).unwrap();
+
+        // Assert
+        assert_eq!(
+            formatter,
+            OutputFormatter::new(None, OutputLayout::Preset(OutputPreset::Sql))
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_output_preset() {
+        // Arrange
+        let matches = arg_matches(&["--output", "rfc9999"]);
+
+        // Act
+        let result = validate_output_options(&matches);
+
+        // Assert
+        assert!(matches!(result, Err(ValidationError::OutputPreset(_))));
+    }
+
+    #[test]
+    fn builds_json_formatter_with_json_flag() {
+        // Arrange
+        let matches = arg_matches(&["--json"]);
+
+        // Act
+        let formatter = validate_output_options(&matches).unwrap();
+
+        // Assert
+        assert_eq!(formatter, OutputFormatter::new(None, OutputLayout::Json));
+    }
+}
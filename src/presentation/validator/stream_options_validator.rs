@@ -0,0 +1,126 @@
+use super::command_options_validator::{validate_request_options, RequestOptions};
+use super::output_options_validator::validate_output_options;
+use super::validation_error::ValidationError;
+use crate::domain::AmbiguousTimeStrategy;
+use crate::presentation::batch::on_error::OnError;
+use crate::presentation::formatter::output_formatter::OutputFormatter;
+use clap::ArgMatches;
+
+/// Where a stream mode writes the translations of each time it reads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum StreamOutput {
+    /// One output line per target and occurrence (batch mode).
+    Lines,
+    /// One new column per target (`--append-column`).
+    AppendedColumns,
+    /// A single value in place of the time (a table cell, a timestamp
+    /// in a log line, a JSON field).
+    InPlace,
+}
+
+/// Raised when the options ask for more translations of each time
+/// than a stream mode has room for.
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub(crate) enum StreamOptionsError {
+    #[error("Validation Error: Translating in place needs exactly one --to timezone, found {0}. Tables can add one column per timezone with --append-column.")]
+    SeveralTargets(usize),
+
+    #[error("Validation Error: --ambiguous-time-strategy both cannot fill a single value. Use earliest, latest or error.")]
+    BothOccurrences,
+}
+
+/// Parses the options every stream mode (batch, table, rewrite,
+/// NDJSON) validates once, before reading any input, and checks they
+/// fit the mode's `output`.
+pub(crate) fn validate_stream_options(
+    arg: &ArgMatches,
+    output: StreamOutput,
+) -> Result<(RequestOptions, OutputFormatter, OnError), ValidationError> {
+    let options: RequestOptions = validate_request_options(arg)?;
+    let formatter: OutputFormatter = validate_output_options(arg)?;
+    let on_error: OnError = arg.get_one::<String>("on_error").unwrap().parse()?;
+
+    if output == StreamOutput::InPlace && options.targets().len() != 1 {
+        return Err(StreamOptionsError::SeveralTargets(options.targets().len()).into());
+    }
+    if output != StreamOutput::Lines && options.strategy() == AmbiguousTimeStrategy::Both {
+        return Err(StreamOptionsError::BothOccurrences.into());
+    }
+
+    Ok((options, formatter, on_error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presentation::stream_fixture::stream_matches;
+
+    /// Fixture: validates `args` as the real CLI would for `output`.
+    fn validate(
+        args: &[&str],
+        output: StreamOutput,
+    ) -> Result<(RequestOptions, OutputFormatter, OnError), ValidationError> {
+        validate_stream_options(&stream_matches(args), output)
+    }
+
+    #[test]
+    fn accepts_several_targets_and_both_occurrences_on_lines() {
+        // Arrange
+        let args = ["-t", "UTC,Asia/Tokyo", "--ambiguous-time-strategy", "both"];
+
+        // Act
+        let result = validate(&args, StreamOutput::Lines);
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn accepts_several_targets_in_appended_columns() {
+        // Arrange
+        let args = ["-t", "UTC,Asia/Tokyo", "--on-error", "skip"];
+
+        // Act
+        let (options, _, on_error) = validate(&args, StreamOutput::AppendedColumns).unwrap();
+
+        // Assert
+        assert_eq!(options.targets().len(), 2);
+        assert_eq!(on_error, OnError::Skip);
+    }
+
+    #[test]
+    fn rejects_several_targets_in_place() {
+        // Arrange
+        let args = ["-t", "UTC,Asia/Tokyo"];
+
+        // Act
+        let result = validate(&args, StreamOutput::InPlace);
+
+        // Assert
+        assert_eq!(
+            result.err(),
+            Some(StreamOptionsError::SeveralTargets(2).into())
+        );
+    }
+
+    #[test]
+    fn rejects_both_occurrences_outside_lines() {
+        // Arrange
+        let args = ["-t", "UTC", "--ambiguous-time-strategy", "both"];
+
+        // Act
+        let results = [
+            validate(&args, StreamOutput::AppendedColumns).err(),
+            validate(&args, StreamOutput::InPlace).err(),
+        ];
+
+        // Assert
+        assert_eq!(
+            results,
+            [
+                Some(StreamOptionsError::BothOccurrences.into()),
+                Some(StreamOptionsError::BothOccurrences.into()),
+            ]
+        );
+    }
+}
@@ -1,8 +1,16 @@
 use crate::domain::{
-    AmbiguousTimeStrategyParseError, ConversionTimeParseError, TimezoneParseError,
+    AmbiguousTimeStrategyParseError, ConversionTimeParseError, EpochTimeParseError,
+    EpochUnitParseError, GapTimeStrategyParseError, InputFormatParseError, ListOrderParseError,
+    OutputFormatParseError, OutputPrecisionParseError, PreferredRegionParseError,
+    RelativeTimeParseError, TimezoneParseError, UtcOffsetParseError,
 };
+use crate::presentation::batch::on_error::OnErrorParseError;
+use crate::presentation::formatter::output_preset::OutputPresetParseError;
+use crate::presentation::ndjson::field_path::FieldPathParseError;
+use crate::presentation::validator::stream_options_validator::StreamOptionsError;
 
-/// Aggregates the domain parse errors that user input can produce.
+/// Aggregates the domain parse errors that user input can produce,
+/// and the options that parse but do not fit together.
 ///
 /// Each variant is transparent: the user-facing message lives with
 /// the value object that failed to parse.
@@ -11,9 +19,51 @@ pub(crate) enum ValidationError {
     #[error(transparent)]
     Time(#[from] ConversionTimeParseError),
 
+    #[error(transparent)]
+    InputFormat(#[from] InputFormatParseError),
+
+    #[error(transparent)]
+    RelativeTime(#[from] RelativeTimeParseError),
+
+    #[error(transparent)]
+    EpochTime(#[from] EpochTimeParseError),
+
+    #[error(transparent)]
+    EpochUnit(#[from] EpochUnitParseError),
+
     #[error(transparent)]
     Timezone(#[from] TimezoneParseError),
 
+    #[error(transparent)]
+    PreferredRegion(#[from] PreferredRegionParseError),
+
     #[error(transparent)]
     AmbiguousTimeStrategy(#[from] AmbiguousTimeStrategyParseError),
+
+    #[error(transparent)]
+    GapTimeStrategy(#[from] GapTimeStrategyParseError),
+
+    #[error(transparent)]
+    OutputPrecision(#[from] OutputPrecisionParseError),
+
+    #[error(transparent)]
+    OutputFormat(#[from] OutputFormatParseError),
+
+    #[error(transparent)]
+    OutputPreset(#[from] OutputPresetParseError),
+
+    #[error(transparent)]
+    OnError(#[from] OnErrorParseError),
+
+    #[error(transparent)]
+    FieldPath(#[from] FieldPathParseError),
+
+    #[error(transparent)]
+    UtcOffset(#[from] UtcOffsetParseError),
+
+    #[error(transparent)]
+    ListOrder(#[from] ListOrderParseError),
+
+    #[error(transparent)]
+    StreamOptions(#[from] StreamOptionsError),
 }
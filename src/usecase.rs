@@ -1,12 +1,45 @@
+pub mod timezone_lister;
+pub mod translation;
 pub mod translation_error;
+pub mod zone_info;
+pub mod zone_inspector;
 
+use self::translation::{Occurrence, Translation};
 use self::translation_error::TranslationError;
-use crate::domain::{AmbiguousTimeStrategy, TranslationRequest};
-use chrono::{DateTime, LocalResult, MappedLocalTime, TimeZone};
-use chrono_tz::Tz;
+use crate::domain::{
+    AmbiguousTimeStrategy, DstGap, GapTimeStrategy, RequestedTime, TranslationRequest, Zone,
+};
+use chrono::{
+    DateTime, FixedOffset, LocalResult, MappedLocalTime, NaiveDateTime, Offset, TimeZone, Utc,
+};
 
-/// Translates the requested wall-clock time from its source timezone
-/// into the target timezone.
+/// A source instant, and the strategy that picked it when the wall
+/// time occurred twice or not at all.
+struct ResolvedSource {
+    time: DateTime<Zone>,
+    ambiguity_resolution: Option<AmbiguousTimeStrategy>,
+    gap_resolution: Option<GapTimeStrategy>,
+}
+
+impl ResolvedSource {
+    fn exact(time: DateTime<Zone>) -> Self {
+        Self {
+            time,
+            ambiguity_resolution: None,
+            gap_resolution: None,
+        }
+    }
+
+    fn picked_by(time: DateTime<Zone>, strategy: AmbiguousTimeStrategy) -> Self {
+        Self {
+            ambiguity_resolution: Some(strategy),
+            ..Self::exact(time)
+        }
+    }
+}
+
+/// Translates the requested time from its source timezone
+/// into each target timezone.
 pub struct TimezoneTranslator {
     request: TranslationRequest,
 }
@@ -16,40 +49,179 @@ impl TimezoneTranslator {
         Self { request }
     }
 
-    /// Attaches the source timezone to the naive time, then converts
-    /// to the target timezone.
+    /// Resolves the requested time to an instant once, then expresses
+    /// that instant in every target timezone, in request order.
+    ///
+    /// A time that carries its own UTC offset, or an epoch time, already
+    /// names an instant, so it bypasses the source timezone and the
+    /// ambiguity strategy.
+    ///
+    /// A time inside a DST gap is resolved by the request's gap
+    /// strategy; an ambiguous time with the `both` strategy resolves to
+    /// both occurrences. The result records which strategy stepped in.
     ///
     /// Returns `TranslationError` when the time does not exist in the
-    /// source timezone (a DST gap).
-    pub fn convert(&self) -> Result<DateTime<Tz>, TranslationError> {
-        let mapped: MappedLocalTime<DateTime<Tz>> = self
+    /// source timezone (a DST gap) and the gap strategy is `error`, when
+    /// it occurs twice and the ambiguity strategy is `error`, or when an
+    /// explicit source timezone disagrees with the offset written in
+    /// the time.
+    pub fn convert(&self) -> Result<Translation, TranslationError> {
+        let occurrences: Vec<Occurrence> = self
+            .resolve_source()?
+            .into_iter()
+            .map(|source| self.translate(source))
+            .collect();
+
+        Ok(Translation::new(self.request.time(), occurrences))
+    }
+
+    /// Expresses one resolved source instant in every target timezone.
+    fn translate(&self, source: ResolvedSource) -> Occurrence {
+        let target_times: Vec<DateTime<Zone>> = self
+            .request
+            .target_timezones()
+            .map(|target| source.time.with_timezone(&target))
+            .collect();
+
+        let occurrence = Occurrence::new(source.time, target_times);
+        match (source.ambiguity_resolution, source.gap_resolution) {
+            (Some(strategy), _) => occurrence.with_ambiguity_resolution(strategy),
+            (_, Some(strategy)) => occurrence.with_gap_resolution(strategy),
+            (None, None) => occurrence,
+        }
+    }
+
+    /// The requested instants, expressed in the source timezone, with
+    /// the strategy applied if the wall time occurred twice or never.
+    fn resolve_source(&self) -> Result<Vec<ResolvedSource>, TranslationError> {
+        match self.request.time() {
+            RequestedTime::Epoch(time) => Ok(vec![ResolvedSource::exact(
+                time.instant()
+                    .with_timezone(&self.request.source_timezone()),
+            )]),
+            RequestedTime::WallClock(time) => match time.offset() {
+                Some(offset) => Ok(vec![ResolvedSource::exact(
+                    self.resolve_with_offset(time.naive_datetime(), offset)?,
+                )]),
+                None => self.resolve_local(time.naive_datetime()),
+            },
+        }
+    }
+
+    fn resolve_local(
+        &self,
+        naive_datetime: NaiveDateTime,
+    ) -> Result<Vec<ResolvedSource>, TranslationError> {
+        let mapped: MappedLocalTime<DateTime<Zone>> = self
             .request
             .source_timezone()
-            .from_local_datetime(&self.request.naive_datetime());
+            .from_local_datetime(&naive_datetime);
 
-        let time_in_source_timezone: DateTime<Tz> = match mapped {
-            LocalResult::Single(time) => time,
+        let resolved: Vec<ResolvedSource> = match mapped {
+            LocalResult::Single(time) => vec![ResolvedSource::exact(time)],
             LocalResult::Ambiguous(earliest, latest) => match self.request.strategy() {
-                AmbiguousTimeStrategy::Earliest => earliest,
-                AmbiguousTimeStrategy::Latest => latest,
+                AmbiguousTimeStrategy::Earliest => vec![ResolvedSource::picked_by(
+                    earliest,
+                    AmbiguousTimeStrategy::Earliest,
+                )],
+                AmbiguousTimeStrategy::Latest => vec![ResolvedSource::picked_by(
+                    latest,
+                    AmbiguousTimeStrategy::Latest,
+                )],
+                AmbiguousTimeStrategy::Both => vec![
+                    ResolvedSource::picked_by(earliest, AmbiguousTimeStrategy::Earliest),
+                    ResolvedSource::picked_by(latest, AmbiguousTimeStrategy::Latest),
+                ],
+                AmbiguousTimeStrategy::Error => {
+                    return Err(TranslationError::AmbiguousTime {
+                        time: naive_datetime,
+                        from_tz: self.request.source_timezone(),
+                        earliest: earliest.to_utc(),
+                        latest: latest.to_utc(),
+                    })
+                }
             },
-            LocalResult::None => {
-                return Err(TranslationError::NonexistentTime {
-                    time: self.request.naive_datetime(),
+            LocalResult::None => vec![self.resolve_gap(naive_datetime)?],
+        };
+
+        Ok(resolved)
+    }
+
+    /// Picks the instant standing in for a wall time inside a DST gap,
+    /// as the gap strategy says.
+    fn resolve_gap(
+        &self,
+        naive_datetime: NaiveDateTime,
+    ) -> Result<ResolvedSource, TranslationError> {
+        let from_tz: Zone = self.request.source_timezone();
+        let strategy: GapTimeStrategy = self.request.gap_strategy();
+        let gap: Option<DstGap> = DstGap::find(from_tz, naive_datetime);
+        let nonexistent = TranslationError::NonexistentTime {
+            time: naive_datetime,
+            from_tz,
+            gap,
+        };
+        let Some(gap) = gap else {
+            return Err(nonexistent);
+        };
+
+        // the offsets on either side of the gap are fixed, so reading the
+        // wall time with one of them always gives exactly one instant
+        let instant: DateTime<Utc> = match strategy {
+            GapTimeStrategy::Error => return Err(nonexistent),
+            GapTimeStrategy::ShiftForward => gap.transition(),
+            GapTimeStrategy::ShiftBackward => gap.nearest_before().to_utc(),
+            GapTimeStrategy::PreTransitionOffset => gap
+                .offset_before()
+                .from_local_datetime(&naive_datetime)
+                .unwrap()
+                .to_utc(),
+            GapTimeStrategy::PostTransitionOffset => gap
+                .offset_after()
+                .from_local_datetime(&naive_datetime)
+                .unwrap()
+                .to_utc(),
+        };
+
+        Ok(ResolvedSource {
+            gap_resolution: Some(strategy),
+            ..ResolvedSource::exact(instant.with_timezone(&from_tz))
+        })
+    }
+
+    fn resolve_with_offset(
+        &self,
+        naive_datetime: NaiveDateTime,
+        offset: FixedOffset,
+    ) -> Result<DateTime<Zone>, TranslationError> {
+        // a fixed offset has no gaps or overlaps, so the mapping is always single
+        let instant: DateTime<FixedOffset> = offset.from_local_datetime(&naive_datetime).unwrap();
+
+        if self.request.source_is_explicit() {
+            let from_tz_offset: FixedOffset = instant
+                .with_timezone(&self.request.source_timezone())
+                .offset()
+                .fix();
+            if from_tz_offset != offset {
+                return Err(TranslationError::OffsetMismatch {
+                    time: naive_datetime,
+                    offset,
                     from_tz: self.request.source_timezone(),
-                    to_tz: self.request.target_timezone(),
-                })
+                    from_tz_offset,
+                });
             }
-        };
+        }
 
-        Ok(time_in_source_timezone.with_timezone(&self.request.target_timezone()))
+        Ok(instant.with_timezone(&self.request.source_timezone()))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::{ConversionTime, EpochTime};
     use chrono::Utc;
+    use chrono_tz::Tz;
 
     /// Fixture: builds a `TranslationRequest` from raw strings.
     fn translation_request(
@@ -59,7 +231,7 @@ mod tests {
         strategy: AmbiguousTimeStrategy,
     ) -> TranslationRequest {
         TranslationRequest::new(
-            time.parse().unwrap(),
+            time.parse::<ConversionTime>().unwrap().into(),
             source.parse().unwrap(),
             target.parse().unwrap(),
             strategy,
@@ -87,7 +259,10 @@ mod tests {
         let converted = TimezoneTranslator::new(request).convert();
 
         // Assert
-        assert_eq!(converted.unwrap(), utc_datetime(2024, 6, 27, 16, 0, 0));
+        assert_eq!(
+            converted.unwrap().target_times(),
+            vec![utc_datetime(2024, 6, 27, 16, 0, 0)]
+        );
     }
 
     #[test]
@@ -106,7 +281,10 @@ mod tests {
         let converted = TimezoneTranslator::new(request).convert();
 
         // Assert
-        assert_eq!(converted.unwrap(), utc_datetime(2024, 11, 3, 5, 30, 0));
+        assert_eq!(
+            converted.unwrap().target_times(),
+            vec![utc_datetime(2024, 11, 3, 5, 30, 0)]
+        );
     }
 
     #[test]
@@ -125,7 +303,10 @@ mod tests {
         let converted = TimezoneTranslator::new(request).convert();
 
         // Assert
-        assert_eq!(converted.unwrap(), utc_datetime(2024, 11, 3, 6, 30, 0));
+        assert_eq!(
+            converted.unwrap().target_times(),
+            vec![utc_datetime(2024, 11, 3, 6, 30, 0)]
+        );
     }
 
     #[test]
@@ -143,6 +324,349 @@ mod tests {
         let converted = TimezoneTranslator::new(request).convert();
 
         // Assert
-        assert!(converted.is_err());
+        assert!(matches!(
+            converted,
+            Err(TranslationError::NonexistentTime { gap: Some(_), .. })
+        ));
+    }
+
+    /// Fixture: 02:30 on 2024-03-10 in New York (inside the 02:00-03:00
+    /// DST gap) translated to UTC with `gap_strategy`.
+    fn new_york_gap_request(gap_strategy: GapTimeStrategy) -> TranslationRequest {
+        translation_request(
+            "2024-03-10 02:30:00",
+            "America/New_York",
+            "UTC",
+            AmbiguousTimeStrategy::Earliest,
+        )
+        .with_gap_strategy(gap_strategy)
+    }
+
+    #[test]
+    fn shifts_gap_time_forward_to_transition() {
+        // Arrange
+        // clocks jump from 02:00 EST to 03:00 EDT, at 07:00 UTC
+        let request = new_york_gap_request(GapTimeStrategy::ShiftForward);
+
+        // Act
+        let converted = TimezoneTranslator::new(request).convert().unwrap();
+
+        // Assert
+        assert_eq!(
+            converted.target_times(),
+            vec![utc_datetime(2024, 3, 10, 7, 0, 0)]
+        );
+        assert_eq!(
+            converted.occurrences()[0].gap_resolution(),
+            Some(GapTimeStrategy::ShiftForward)
+        );
+    }
+
+    #[test]
+    fn shifts_gap_time_backward_to_just_before_transition() {
+        // Arrange
+        let request = new_york_gap_request(GapTimeStrategy::ShiftBackward);
+
+        // Act
+        let converted = TimezoneTranslator::new(request).convert().unwrap();
+
+        // Assert
+        assert_eq!(
+            converted.target_times(),
+            vec![utc_datetime(2024, 3, 10, 6, 59, 59)]
+        );
+    }
+
+    #[test]
+    fn reads_gap_time_with_offset_before_transition() {
+        // Arrange
+        // 02:30 at EST (UTC-5)
+        let request = new_york_gap_request(GapTimeStrategy::PreTransitionOffset);
+
+        // Act
+        let converted = TimezoneTranslator::new(request).convert().unwrap();
+
+        // Assert
+        assert_eq!(
+            converted.target_times(),
+            vec![utc_datetime(2024, 3, 10, 7, 30, 0)]
+        );
+    }
+
+    #[test]
+    fn reads_gap_time_with_offset_after_transition() {
+        // Arrange
+        // 02:30 at EDT (UTC-4)
+        let request = new_york_gap_request(GapTimeStrategy::PostTransitionOffset);
+
+        // Act
+        let converted = TimezoneTranslator::new(request).convert().unwrap();
+
+        // Assert
+        assert_eq!(
+            converted.target_times(),
+            vec![utc_datetime(2024, 3, 10, 6, 30, 0)]
+        );
+    }
+
+    #[test]
+    fn records_no_gap_resolution_for_existing_time() {
+        // Arrange
+        let request = translation_request(
+            "2024-03-10 03:30:00",
+            "America/New_York",
+            "UTC",
+            AmbiguousTimeStrategy::Earliest,
+        )
+        .with_gap_strategy(GapTimeStrategy::ShiftForward);
+
+        // Act
+        let converted = TimezoneTranslator::new(request).convert().unwrap();
+
+        // Assert
+        assert_eq!(converted.occurrences()[0].gap_resolution(), None);
+    }
+
+    #[test]
+    fn uses_offset_in_time_instead_of_defaulted_source_timezone() {
+        // Arrange
+        // the source timezone is only a default, so the +09:00 in the input wins
+        let request = translation_request(
+            "2024-06-27T12:00:00+09:00",
+            "America/New_York",
+            "UTC",
+            AmbiguousTimeStrategy::Earliest,
+        )
+        .with_explicit_source(false);
+
+        // Act
+        let converted = TimezoneTranslator::new(request).convert();
+
+        // Assert
+        assert_eq!(
+            converted.unwrap().target_times(),
+            vec![utc_datetime(2024, 6, 27, 3, 0, 0)]
+        );
+    }
+
+    #[test]
+    fn accepts_offset_that_agrees_with_explicit_source_timezone() {
+        // Arrange
+        // Tokyo is UTC+9 all year
+        let request = translation_request(
+            "2024-06-27T12:00:00+09:00",
+            "Asia/Tokyo",
+            "UTC",
+            AmbiguousTimeStrategy::Earliest,
+        );
+
+        // Act
+        let converted = TimezoneTranslator::new(request).convert();
+
+        // Assert
+        assert_eq!(
+            converted.unwrap().target_times(),
+            vec![utc_datetime(2024, 6, 27, 3, 0, 0)]
+        );
+    }
+
+    #[test]
+    fn fails_when_offset_disagrees_with_explicit_source_timezone() {
+        // Arrange
+        // New York is UTC-4 on 2024-06-27, not UTC+9
+        let request = translation_request(
+            "2024-06-27T12:00:00+09:00",
+            "America/New_York",
+            "UTC",
+            AmbiguousTimeStrategy::Earliest,
+        );
+
+        // Act
+        let converted = TimezoneTranslator::new(request).convert();
+
+        // Assert
+        assert!(matches!(
+            converted,
+            Err(TranslationError::OffsetMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn converts_epoch_time_without_consulting_source_timezone() {
+        // Arrange
+        // 1719491696 is 2024-06-27 12:34:56 UTC, whatever the source timezone says
+        let request = TranslationRequest::new(
+            "@1719491696".parse::<EpochTime>().unwrap().into(),
+            "America/New_York".parse().unwrap(),
+            "UTC".parse().unwrap(),
+            AmbiguousTimeStrategy::Earliest,
+        );
+
+        // Act
+        let converted = TimezoneTranslator::new(request).convert();
+
+        // Assert
+        assert_eq!(
+            converted.unwrap().target_times(),
+            vec![utc_datetime(2024, 6, 27, 12, 34, 56)]
+        );
+    }
+
+    #[test]
+    fn converts_between_fixed_offsets_without_dst() {
+        // Arrange
+        // a fixed offset has no DST, so 2024-03-10 02:30 (a gap in New York) exists
+        let request = translation_request(
+            "2024-03-10 02:30:00",
+            "-05:00",
+            "+05:30",
+            AmbiguousTimeStrategy::Earliest,
+        );
+
+        // Act
+        let converted = TimezoneTranslator::new(request).convert();
+
+        // Assert
+        assert_eq!(
+            converted.unwrap().target_times(),
+            vec![utc_datetime(2024, 3, 10, 7, 30, 0)]
+        );
+    }
+
+    #[test]
+    fn converts_into_every_target_timezone_in_order() {
+        // Arrange
+        let request = translation_request(
+            "2024-06-27 12:00:00",
+            "UTC",
+            "Asia/Tokyo",
+            AmbiguousTimeStrategy::Earliest,
+        )
+        .with_additional_targets(vec![
+            "Europe/London".parse().unwrap(),
+            "UTC".parse().unwrap(),
+        ]);
+
+        // Act
+        let converted = TimezoneTranslator::new(request).convert().unwrap();
+
+        // Assert
+        let zones: Vec<String> = converted
+            .target_times()
+            .iter()
+            .map(|time| time.timezone().to_string())
+            .collect();
+        assert_eq!(zones, vec!["Asia/Tokyo", "Europe/London", "UTC"]);
+        assert!(converted
+            .target_times()
+            .iter()
+            .all(|time| *time == utc_datetime(2024, 6, 27, 12, 0, 0)));
+    }
+
+    #[test]
+    fn records_strategy_only_when_time_was_ambiguous() {
+        // Arrange
+        let ambiguous = translation_request(
+            "2024-11-03 01:30:00",
+            "America/New_York",
+            "UTC",
+            AmbiguousTimeStrategy::Latest,
+        );
+        let unambiguous = translation_request(
+            "2024-06-27 12:00:00",
+            "America/New_York",
+            "UTC",
+            AmbiguousTimeStrategy::Latest,
+        );
+
+        // Act
+        let ambiguous = TimezoneTranslator::new(ambiguous).convert().unwrap();
+        let unambiguous = TimezoneTranslator::new(unambiguous).convert().unwrap();
+
+        // Assert
+        assert_eq!(
+            ambiguous.occurrences()[0].ambiguity_resolution(),
+            Some(AmbiguousTimeStrategy::Latest)
+        );
+        assert_eq!(unambiguous.occurrences()[0].ambiguity_resolution(), None);
+    }
+
+    #[test]
+    fn translates_both_occurrences_with_both_strategy() {
+        // Arrange
+        let request = translation_request(
+            "2024-11-03 01:30:00",
+            "America/New_York",
+            "UTC",
+            AmbiguousTimeStrategy::Both,
+        );
+
+        // Act
+        let converted = TimezoneTranslator::new(request).convert().unwrap();
+
+        // Assert
+        assert_eq!(
+            converted.target_times(),
+            vec![
+                utc_datetime(2024, 11, 3, 5, 30, 0),
+                utc_datetime(2024, 11, 3, 6, 30, 0)
+            ]
+        );
+        let resolutions: Vec<Option<AmbiguousTimeStrategy>> = converted
+            .occurrences()
+            .iter()
+            .map(|occurrence| occurrence.ambiguity_resolution())
+            .collect();
+        assert_eq!(
+            resolutions,
+            vec![
+                Some(AmbiguousTimeStrategy::Earliest),
+                Some(AmbiguousTimeStrategy::Latest)
+            ]
+        );
+    }
+
+    #[test]
+    fn fails_on_ambiguous_time_with_error_strategy() {
+        // Arrange
+        let request = translation_request(
+            "2024-11-03 01:30:00",
+            "America/New_York",
+            "UTC",
+            AmbiguousTimeStrategy::Error,
+        );
+
+        // Act
+        let converted = TimezoneTranslator::new(request).convert();
+
+        // Assert
+        let Err(TranslationError::AmbiguousTime {
+            earliest, latest, ..
+        }) = converted
+        else {
+            panic!("expected AmbiguousTime, got {:?}", converted);
+        };
+        assert_eq!(earliest, utc_datetime(2024, 11, 3, 5, 30, 0));
+        assert_eq!(latest, utc_datetime(2024, 11, 3, 6, 30, 0));
+    }
+
+    #[test]
+    fn error_strategy_accepts_unambiguous_time() {
+        // Arrange
+        let request = translation_request(
+            "2024-06-27 12:00:00",
+            "America/New_York",
+            "UTC",
+            AmbiguousTimeStrategy::Error,
+        );
+
+        // Act
+        let converted = TimezoneTranslator::new(request).convert();
+
+        // Assert
+        assert_eq!(
+            converted.unwrap().target_times(),
+            vec![utc_datetime(2024, 6, 27, 16, 0, 0)]
+        );
     }
 }
@@ -0,0 +1,110 @@
+use crate::domain::{Clock, ListOrder, ListRequest, Zone};
+use chrono::{DateTime, Offset};
+use chrono_tz::TZ_VARIANTS;
+
+/// Lists the IANA timezones that a `ListRequest` admits (`tzt list`).
+pub struct TimezoneLister {
+    request: ListRequest,
+}
+
+impl TimezoneLister {
+    pub fn new(request: ListRequest) -> Self {
+        Self { request }
+    }
+
+    /// The current instant in every admitted timezone, in the order
+    /// the request asks for. Each carries the zone's current offset,
+    /// abbreviation and DST state.
+    ///
+    /// Offsets change with DST, so the offset filter and order depend
+    /// on when the list is taken: `clock` says when that is.
+    pub fn list(&self, clock: &impl Clock) -> Vec<DateTime<Zone>> {
+        let now = clock.now();
+        let mut listed: Vec<DateTime<Zone>> = TZ_VARIANTS
+            .iter()
+            .map(|tz| now.with_timezone(&Zone::Iana(*tz)))
+            .filter(|time| match time.timezone() {
+                Zone::Iana(tz) => self.request.admits(tz, time.offset().fix()),
+                Zone::Fixed(_) => false,
+            })
+            .collect();
+
+        match self.request.order() {
+            ListOrder::Name => listed.sort_by_cached_key(|time| time.timezone().to_string()),
+            ListOrder::Offset => listed.sort_by_cached_key(|time| {
+                (
+                    time.offset().fix().local_minus_utc(),
+                    time.timezone().to_string(),
+                )
+            }),
+        }
+        listed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::FixedClock;
+
+    fn names(listed: &[DateTime<Zone>]) -> Vec<String> {
+        listed
+            .iter()
+            .map(|time| time.timezone().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn lists_every_timezone_by_name() {
+        // Arrange
+        let lister = TimezoneLister::new(ListRequest::default());
+
+        // Act
+        let listed = lister.list(&FixedClock::fixture());
+
+        // Assert
+        assert_eq!(listed.len(), TZ_VARIANTS.len());
+        let names = names(&listed);
+        assert!(names.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn filters_by_current_offset() {
+        // Arrange
+        // London is at +01:00 (BST) in June
+        let request = ListRequest::default()
+            .with_region(Some("Europe".parse().unwrap()))
+            .with_offset(Some("+01:00".parse().unwrap()));
+
+        // Act
+        let listed = TimezoneLister::new(request).list(&FixedClock::fixture());
+
+        // Assert
+        assert!(names(&listed).contains(&"Europe/London".to_string()));
+        assert!(!names(&listed).contains(&"Europe/Paris".to_string()));
+    }
+
+    #[test]
+    fn sorts_by_offset_then_name() {
+        // Arrange
+        let request = ListRequest::default()
+            .with_pattern(Some("america/new_york".to_string()))
+            .with_order(ListOrder::Offset);
+        let asia_request = ListRequest::default()
+            .with_pattern(Some("o".to_string()))
+            .with_region(Some("Asia".parse().unwrap()))
+            .with_order(ListOrder::Offset);
+
+        // Act
+        let new_york = TimezoneLister::new(request).list(&FixedClock::fixture());
+        let asia = TimezoneLister::new(asia_request).list(&FixedClock::fixture());
+
+        // Assert
+        assert_eq!(names(&new_york), ["America/New_York"]);
+        let offsets: Vec<i32> = asia
+            .iter()
+            .map(|time| time.offset().fix().local_minus_utc())
+            .collect();
+        assert!(offsets.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+}
@@ -0,0 +1,100 @@
+use crate::domain::{AmbiguousTimeStrategy, GapTimeStrategy, RequestedTime, Zone};
+use chrono::DateTime;
+
+/// The outcome of a translation: every instant the requested time
+/// resolved to, each expressed in every target timezone.
+///
+/// There is one occurrence, except for an ambiguous time translated
+/// with the `both` strategy, which has two (earliest first).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Translation {
+    input: RequestedTime,
+    occurrences: Vec<Occurrence>,
+}
+
+/// One resolved source instant, its image in every target timezone,
+/// and how it was resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Occurrence {
+    source_time: DateTime<Zone>,
+    target_times: Vec<DateTime<Zone>>,
+    ambiguity_resolution: Option<AmbiguousTimeStrategy>,
+    gap_resolution: Option<GapTimeStrategy>,
+}
+
+impl Translation {
+    pub fn new(input: RequestedTime, occurrences: Vec<Occurrence>) -> Self {
+        Self { input, occurrences }
+    }
+
+    /// The time as requested, before resolution.
+    pub fn input(&self) -> RequestedTime {
+        self.input
+    }
+
+    /// The resolved occurrences, earliest first.
+    pub fn occurrences(&self) -> &[Occurrence] {
+        &self.occurrences
+    }
+
+    /// The instant in each target timezone, in request order, for each
+    /// occurrence in turn.
+    pub fn target_times(&self) -> Vec<DateTime<Zone>> {
+        self.occurrences
+            .iter()
+            .flat_map(|occurrence| occurrence.target_times.iter().copied())
+            .collect()
+    }
+}
+
+impl Occurrence {
+    pub fn new(source_time: DateTime<Zone>, target_times: Vec<DateTime<Zone>>) -> Self {
+        Self {
+            source_time,
+            target_times,
+            ambiguity_resolution: None,
+            gap_resolution: None,
+        }
+    }
+
+    /// Records that the input occurred twice and `strategy` picked this
+    /// occurrence.
+    pub fn with_ambiguity_resolution(self, strategy: AmbiguousTimeStrategy) -> Self {
+        Self {
+            ambiguity_resolution: Some(strategy),
+            ..self
+        }
+    }
+
+    /// Records that the input fell into a DST gap and `strategy`
+    /// picked the instant that stands in for it.
+    pub fn with_gap_resolution(self, strategy: GapTimeStrategy) -> Self {
+        Self {
+            gap_resolution: Some(strategy),
+            ..self
+        }
+    }
+
+    /// The resolved instant, in the source timezone.
+    pub fn source_time(&self) -> &DateTime<Zone> {
+        &self.source_time
+    }
+
+    /// The instant in each target timezone, in request order.
+    pub fn target_times(&self) -> &[DateTime<Zone>] {
+        &self.target_times
+    }
+
+    /// The strategy that picked this occurrence, when the input was
+    /// an ambiguous local time (`earliest` or `latest`); `None` when
+    /// there was nothing to choose.
+    pub fn ambiguity_resolution(&self) -> Option<AmbiguousTimeStrategy> {
+        self.ambiguity_resolution
+    }
+
+    /// The strategy that replaced a nonexistent local time; `None`
+    /// when the time existed.
+    pub fn gap_resolution(&self) -> Option<GapTimeStrategy> {
+        self.gap_resolution
+    }
+}
@@ -1,15 +1,42 @@
-use chrono::NaiveDateTime;
-use chrono_tz::Tz;
+use crate::domain::{DstGap, Zone};
+use chrono::{DateTime, FixedOffset, NaiveDateTime, Utc};
 
 #[derive(thiserror::Error, Debug)]
 pub enum TranslationError {
     /// The requested wall-clock time falls into a DST gap and never
-    /// existed in the source timezone. The fields record the inputs
-    /// for debugging; the message stays generic for the CLI user.
-    #[error("Translation Error: Output time and timezone does not exist. Please check DST rules.")]
+    /// existed in the source timezone. The message describes the gap
+    /// when the tz data knows its edges.
+    #[error(
+        "Translation Error: {time} does not exist in {from_tz}. {} Use --gap-strategy to pick a time instead.",
+        gap.map_or("Please check DST rules.".to_string(), |gap| gap.to_string())
+    )]
     NonexistentTime {
         time: NaiveDateTime,
-        from_tz: Tz,
-        to_tz: Tz,
+        from_tz: Zone,
+        gap: Option<DstGap>,
+    },
+
+    /// The requested wall-clock time occurs twice in the source
+    /// timezone (DST ends) and the ambiguity strategy is `error`.
+    #[error(
+        "Translation Error: {time} occurs twice in {from_tz}, at {} and at {}. Use --ambiguous-time-strategy earliest, latest or both.",
+        earliest.with_timezone(from_tz).format("%Y-%m-%dT%H:%M:%S%:z %Z"),
+        latest.with_timezone(from_tz).format("%Y-%m-%dT%H:%M:%S%:z %Z")
+    )]
+    AmbiguousTime {
+        time: NaiveDateTime,
+        from_tz: Zone,
+        earliest: DateTime<Utc>,
+        latest: DateTime<Utc>,
+    },
+
+    /// The input time carried its own UTC offset, but the explicitly
+    /// given source timezone is at a different offset at that instant.
+    #[error("Translation Error: The offset {offset} in {time} disagrees with {from_tz}, which is at {from_tz_offset} at that instant. Drop --from or fix the offset.")]
+    OffsetMismatch {
+        time: NaiveDateTime,
+        offset: FixedOffset,
+        from_tz: Zone,
+        from_tz_offset: FixedOffset,
     },
 }
@@ -0,0 +1,82 @@
+use crate::domain::zone::ZoneOffset;
+use crate::domain::{Zone, ZoneTransition};
+use chrono::{DateTime, FixedOffset, Offset};
+
+/// A zone as it stands at one instant (`tzt info`): its current
+/// offset, and the transitions on either side.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZoneInfo {
+    now: DateTime<Zone>,
+    previous_transition: Option<ZoneTransition>,
+    next_transition: Option<ZoneTransition>,
+}
+
+impl ZoneInfo {
+    pub fn new(
+        now: DateTime<Zone>,
+        previous_transition: Option<ZoneTransition>,
+        next_transition: Option<ZoneTransition>,
+    ) -> Self {
+        Self {
+            now,
+            previous_transition,
+            next_transition,
+        }
+    }
+
+    pub fn zone(&self) -> Zone {
+        self.now.timezone()
+    }
+
+    /// The current instant in the zone, with its offset, abbreviation
+    /// and DST state.
+    pub fn now(&self) -> &DateTime<Zone> {
+        &self.now
+    }
+
+    /// The transition that put the current offset in effect.
+    pub fn previous_transition(&self) -> Option<&ZoneTransition> {
+        self.previous_transition.as_ref()
+    }
+
+    /// The next scheduled transition.
+    pub fn next_transition(&self) -> Option<&ZoneTransition> {
+        self.next_transition.as_ref()
+    }
+
+    /// Whether the current offset is the zone's daylight offset.
+    pub fn is_dst(&self) -> bool {
+        self.daylight_offset() == Some(self.now.offset().fix())
+    }
+
+    /// The offset outside DST: the smaller of the two the zone
+    /// alternates between, or the current one without DST.
+    pub fn standard_offset(&self) -> FixedOffset {
+        match self.seasonal_offset() {
+            Some(offset) => offset.standard_offset(),
+            None => self.now.offset().standard_offset(),
+        }
+    }
+
+    /// The offset while DST is in effect: the larger of the two the
+    /// zone alternates between. `None` when the zone does not observe
+    /// DST, including a zone that dropped it long ago (Asia/Tokyo).
+    pub fn daylight_offset(&self) -> Option<FixedOffset> {
+        self.seasonal_offset()
+            .and_then(|offset| offset.daylight_offset())
+    }
+
+    /// The current offset or the one the next transition brings,
+    /// whichever carries a DST shift. Europe/Dublin shifts its winter
+    /// offset back rather than its summer one ahead, so in summer only
+    /// the next transition shows it observes DST.
+    fn seasonal_offset(&self) -> Option<ZoneOffset> {
+        let next = self
+            .next_transition
+            .map(|transition| *transition.after().offset());
+
+        std::iter::once(*self.now.offset())
+            .chain(next)
+            .find(|offset| offset.daylight_offset().is_some())
+    }
+}
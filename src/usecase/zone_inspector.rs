@@ -0,0 +1,122 @@
+use super::zone_info::ZoneInfo;
+use crate::domain::{Clock, SourceTimezone, Zone, ZoneTransition};
+use chrono::{DateTime, SubsecRound, Utc};
+
+/// Describes a timezone as it stands now (`tzt info`).
+pub struct ZoneInspector {
+    zone: Zone,
+}
+
+impl ZoneInspector {
+    pub fn new(timezone: SourceTimezone) -> Self {
+        Self {
+            zone: timezone.timezone(),
+        }
+    }
+
+    /// The zone's current offset and DST state, and the transitions
+    /// before and after `clock`'s "now", taken to the second.
+    pub fn inspect(&self, clock: &impl Clock) -> ZoneInfo {
+        let now: DateTime<Utc> = clock.now().trunc_subsecs(0);
+
+        ZoneInfo::new(
+            now.with_timezone(&self.zone),
+            ZoneTransition::previous_before(self.zone, now),
+            ZoneTransition::next_after(self.zone, now),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::FixedClock;
+    use chrono::{Offset, TimeZone};
+
+    fn inspect(zone: &str) -> ZoneInfo {
+        ZoneInspector::new(zone.parse().unwrap()).inspect(&FixedClock::fixture())
+    }
+
+    #[test]
+    fn describes_zone_on_summer_time() {
+        // Arrange
+        let zone = "Europe/London";
+
+        // Act
+        let info = inspect(zone);
+
+        // Assert
+        assert!(info.is_dst());
+        assert_eq!(info.now().to_string(), "2024-06-27 13:00:00 BST");
+        assert_eq!(info.standard_offset().local_minus_utc(), 0);
+        assert_eq!(info.daylight_offset().unwrap().local_minus_utc(), 3600);
+        assert_eq!(
+            info.next_transition().unwrap().instant(),
+            Utc.with_ymd_and_hms(2024, 10, 27, 1, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn finds_daylight_offset_out_of_season() {
+        // Arrange
+        // Sydney is on standard time (AEST) in June
+        let zone = "Australia/Sydney";
+
+        // Act
+        let info = inspect(zone);
+
+        // Assert
+        assert!(!info.is_dst());
+        assert_eq!(info.now().offset().fix().local_minus_utc(), 10 * 3600);
+        assert_eq!(info.daylight_offset().unwrap().local_minus_utc(), 11 * 3600);
+    }
+
+    #[test]
+    fn describes_dublin_summer_time_as_dst() {
+        // Arrange
+        // the tz data shifts Dublin's winter offset back instead of its
+        // summer offset ahead
+        let zone = "Europe/Dublin";
+
+        // Act
+        let info = inspect(zone);
+
+        // Assert
+        assert!(info.is_dst());
+        assert_eq!(info.now().to_string(), "2024-06-27 13:00:00 IST");
+        assert_eq!(info.standard_offset().local_minus_utc(), 0);
+        assert_eq!(info.daylight_offset().unwrap().local_minus_utc(), 3600);
+    }
+
+    #[test]
+    fn describes_dublin_winter_time_as_standard() {
+        // Arrange
+        let inspector = ZoneInspector::new("Europe/Dublin".parse().unwrap());
+        let winter = FixedClock(Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap());
+
+        // Act
+        let info = inspector.inspect(&winter);
+
+        // Assert
+        assert!(!info.is_dst());
+        assert_eq!(info.now().to_string(), "2024-01-15 12:00:00 GMT");
+        assert_eq!(info.standard_offset().local_minus_utc(), 0);
+        assert_eq!(info.daylight_offset().unwrap().local_minus_utc(), 3600);
+    }
+
+    #[test]
+    fn describes_fixed_offset_without_transitions() {
+        // Arrange
+        let zone = "+05:30";
+
+        // Act
+        let info = inspect(zone);
+
+        // Assert
+        assert!(!info.is_dst());
+        assert_eq!(info.standard_offset().local_minus_utc(), 5 * 3600 + 30 * 60);
+        assert_eq!(info.daylight_offset(), None);
+        assert_eq!(info.previous_transition(), None);
+        assert_eq!(info.next_transition(), None);
+    }
+}
@@ -160,8 +160,1277 @@ fn fails_when_time_does_not_exist_due_to_dst_gap() {
         "America/Los_Angeles",
     ]);
 
+    // Act & Assert
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Translation Error"))
+        .stderr(predicate::str::contains(
+            "jump from 2024-03-10 02:00:00 to 2024-03-10 03:00:00",
+        ))
+        .stderr(predicate::str::contains(
+            "The nearest valid times are 2024-03-10 01:59:59 EST and 2024-03-10 03:00:00 EDT.",
+        ));
+}
+
+#[test]
+fn uses_utc_offset_written_in_time() {
+    // Arrange
+    // no --from: the +09:00 in the input decides the instant
+    let mut cmd = tzt();
+    cmd.args(["-T", "2024-06-27T12:34:56+09:00", "-t", "UTC"]);
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2024-06-27 03:34:56 UTC"));
+}
+
+#[test]
+fn accepts_zulu_suffix_in_time() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args(["-T", "2024-06-27T12:34:56Z", "-t", "Asia/Tokyo"]);
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2024-06-27 21:34:56 JST"));
+}
+
+#[test]
+fn fails_when_offset_disagrees_with_explicit_source_timezone() {
+    // Arrange
+    // New York is UTC-4 in June, so +09:00 contradicts --from
+    let mut cmd = tzt();
+    cmd.args([
+        "-T",
+        "2024-06-27T12:34:56+09:00",
+        "-f",
+        "America/New_York",
+        "-t",
+        "UTC",
+    ]);
+
     // Act & Assert
     cmd.assert()
         .failure()
         .stderr(predicate::str::contains("Translation Error"));
 }
+
+#[test]
+fn keeps_microseconds_from_input() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args([
+        "-T",
+        "2024-01-01 12:00:00.789123",
+        "-f",
+        "UTC",
+        "-t",
+        "Asia/Tokyo",
+    ]);
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2024-01-01 21:00:00.789123 JST"));
+}
+
+#[test]
+fn prints_fixed_fractional_digits_with_precision() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args([
+        "-T",
+        "2024-01-01 12:00:00.5",
+        "-f",
+        "UTC",
+        "-t",
+        "Asia/Tokyo",
+        "--precision",
+        "9",
+    ]);
+
+    // Act & Assert
+    cmd.assert().success().stdout(predicate::str::contains(
+        "2024-01-01 21:00:00.500000000 JST",
+    ));
+}
+
+#[test]
+fn fails_with_invalid_precision() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args(["-T", "2024-01-01 12:00:00", "--precision", "10"]);
+
+    // Act & Assert
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Validation Error"));
+}
+
+#[test]
+fn reads_at_prefixed_time_as_epoch_seconds() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args(["-T", "@1719491696", "-t", "Asia/Tokyo"]);
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2024-06-27 21:34:56 JST"));
+}
+
+#[test]
+fn reads_time_as_epoch_in_given_unit() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args(["-T", "1719491696789", "--time-unit", "ms", "-t", "UTC"]);
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2024-06-27 12:34:56.789 UTC"));
+}
+
+#[test]
+fn prints_result_as_epoch_in_given_unit() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args([
+        "-T",
+        "2024-06-27 21:34:56",
+        "-f",
+        "Asia/Tokyo",
+        "-t",
+        "UTC",
+        "--epoch-output",
+        "s",
+    ]);
+
+    // Act & Assert
+    cmd.assert().success().stdout("1719491696\n");
+}
+
+#[test]
+fn translates_current_time_given_as_now() {
+    // Arrange
+    // the exact output depends on the clock, so only the target
+    // abbreviation is checked
+    let mut cmd = tzt();
+    cmd.args(["-T", "now", "-t", "Asia/Tokyo"]);
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::ends_with("JST\n"));
+}
+
+#[test]
+fn rejects_missing_time() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args(["-t", "Asia/Tokyo"]);
+
+    // Act & Assert
+    cmd.assert()
+        .code(2)
+        .stderr(predicate::str::contains("--time <TIME>"));
+}
+
+#[test]
+fn accepts_relative_time_expression() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args(["-T", "tomorrow 09:00", "-f", "Asia/Tokyo", "-t", "UTC"]);
+
+    // Act & Assert
+    // 09:00 in Tokyo is always 00:00 UTC
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(" 00:00:00 UTC"));
+}
+
+#[test]
+fn fails_with_invalid_relative_time_expression() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args(["-T", "tomorrow 9am", "-f", "UTC", "-t", "UTC"]);
+
+    // Act & Assert
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid relative time"));
+}
+
+#[test]
+fn reads_time_with_user_input_format() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args([
+        "-T",
+        "27/06/2024 12:34",
+        "--input-format",
+        "%d/%m/%Y %H:%M",
+        "-f",
+        "UTC",
+        "-t",
+        "Asia/Tokyo",
+    ]);
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2024-06-27 21:34:00 JST"));
+}
+
+#[test]
+fn lists_attempted_formats_when_time_matches_none() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args([
+        "-T",
+        "Jun 27 2024",
+        "--input-format",
+        "%d/%m/%Y %H:%M",
+        "-f",
+        "UTC",
+    ]);
+
+    // Act & Assert
+    cmd.assert().failure().stderr(
+        predicate::str::contains("%d/%m/%Y %H:%M")
+            .and(predicate::str::contains("YYYY-MM-DD hh:mm:ss")),
+    );
+}
+
+#[test]
+fn translates_between_timezone_abbreviations() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args(["-T", "2024-06-27 15:00:00", "-f", "EST", "-t", "JST"]);
+
+    // Act & Assert
+    // EST stays UTC-5 even in summer
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2024-06-28 05:00:00 JST"));
+}
+
+#[test]
+fn keeps_cet_at_one_offset_in_summer() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args(["-T", "2024-07-01 12:00:00", "-f", "CET", "-t", "UTC"]);
+
+    // Act & Assert
+    // the tz data's `CET` zone would read this as CEST (UTC+2)
+    cmd.assert().success().stdout("2024-07-01 11:00:00 UTC\n");
+}
+
+#[test]
+fn lists_candidates_of_ambiguous_abbreviation() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args(["-T", "2024-06-27 15:00:00", "-f", "IST", "-t", "UTC"]);
+
+    // Act & Assert
+    cmd.assert().failure().stderr(
+        predicate::str::contains("Asia/Kolkata")
+            .and(predicate::str::contains("Europe/Dublin"))
+            .and(predicate::str::contains("Asia/Jerusalem")),
+    );
+}
+
+#[test]
+fn settles_ambiguous_abbreviation_with_preferred_region() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args([
+        "-T",
+        "2024-06-27 15:00:00",
+        "-f",
+        "IST",
+        "-t",
+        "UTC",
+        "--prefer-region",
+        "Europe",
+    ]);
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2024-06-27 14:00:00 UTC"));
+}
+
+#[test]
+fn translates_between_fixed_utc_offsets() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args(["-T", "2024-06-27 12:00:00", "-f", "UTC-3", "-t", "+05:30"]);
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2024-06-27 20:30:00 +05:30"));
+}
+
+#[test]
+fn reads_negative_utc_offset_as_timezone_value() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args(["-T", "2024-06-27 12:00:00", "-f", "-05:00", "-t", "UTC"]);
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2024-06-27 17:00:00 UTC"));
+}
+
+#[test]
+fn matches_timezone_name_regardless_of_case() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args(["-T", "2024-01-01 12:00:00", "-f", "utc", "-t", "asia/tokyo"]);
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2024-01-01 21:00:00 JST"));
+}
+
+#[test]
+fn suggests_close_timezone_name() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args([
+        "-T",
+        "2024-01-01 12:00:00",
+        "-f",
+        "UTC",
+        "-t",
+        "Europe/Londn",
+    ]);
+
+    // Act & Assert
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("did you mean Europe/London?"));
+}
+
+#[test]
+fn requires_exact_timezone_name_with_strict_flag() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args([
+        "-T",
+        "2024-01-01 12:00:00",
+        "-f",
+        "UTC",
+        "-t",
+        "asia/tokyo",
+        "--strict",
+    ]);
+
+    // Act & Assert
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Invalid timezone found asia/tokyo",
+    ));
+}
+
+#[test]
+fn translates_between_city_and_country() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args(["-T", "2024-06-27 12:00:00", "-f", "Bangalore", "-t", "JP"]);
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2024-06-27 15:30:00 JST"));
+}
+
+#[test]
+fn lists_zones_of_country_spanning_several() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args(["-T", "2024-06-27 12:00:00", "-f", "UTC", "-t", "US"]);
+
+    // Act & Assert
+    cmd.assert().failure().stderr(
+        predicate::str::contains("America/New_York")
+            .and(predicate::str::contains("America/Los_Angeles")),
+    );
+}
+
+#[test]
+fn prints_one_line_per_target_timezone_in_order() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args([
+        "-T",
+        "2024-06-27 09:00:00",
+        "-f",
+        "America/New_York",
+        "-t",
+        "Europe/London,Asia/Tokyo",
+        "-t",
+        "UTC",
+    ]);
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout("2024-06-27 14:00:00 BST\n2024-06-27 22:00:00 JST\n2024-06-27 13:00:00 UTC\n");
+}
+
+#[test]
+fn prints_result_with_output_format() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args([
+        "-T",
+        "2024-01-01 12:00:00",
+        "-f",
+        "UTC",
+        "-t",
+        "Asia/Tokyo",
+        "--format",
+        "%Y%m%dT%H%M%S",
+    ]);
+
+    // Act & Assert
+    cmd.assert().success().stdout("20240101T210000\n");
+}
+
+#[test]
+fn fails_with_invalid_output_format() {
+    // Arrange
+    let mut cmd = tzt();
+    cmd.args(["-T", "2024-01-01 12:00:00", "-f", "UTC", "--format", "%Q"]);
+
+    // Act & Assert
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid output format"));
+}
+
+/// Fixture: tzt translating 2024-01-01 12:00:00 UTC to Asia/Tokyo with `--output preset`.
+fn tzt_with_output_preset(preset: &str) -> Command {
+    let mut cmd = tzt();
+    cmd.args([
+        "-T",
+        "2024-01-01 12:00:00",
+        "-f",
+        "UTC",
+        "-t",
+        "Asia/Tokyo",
+        "--output",
+        preset,
+    ]);
+    cmd
+}
+
+#[test]
+fn prints_rfc3339_preset() {
+    // Arrange
+    let mut cmd = tzt_with_output_preset("rfc3339");
+
+    // Act & Assert
+    cmd.assert().success().stdout("2024-01-01T21:00:00+09:00\n");
+}
+
+#[test]
+fn prints_rfc2822_preset() {
+    // Arrange
+    let mut cmd = tzt_with_output_preset("rfc2822");
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout("Mon, 1 Jan 2024 21:00:00 +0900\n");
+}
+
+#[test]
+fn rejects_presets_outside_four_digit_years() {
+    // Arrange
+    // @999999999999 is in the year 33658
+    let mut cmd = tzt();
+    cmd.args([
+        "-T",
+        "@999999999999",
+        "-f",
+        "UTC",
+        "-t",
+        "UTC",
+        "-o",
+        "rfc3339",
+    ]);
+
+    // Act & Assert
+    cmd.assert()
+        .code(61)
+        .stderr(predicate::str::contains("cannot be printed as rfc3339"));
+}
+
+#[test]
+fn prints_iso8601_basic_preset() {
+    // Arrange
+    let mut cmd = tzt_with_output_preset("iso8601-basic");
+
+    // Act & Assert
+    cmd.assert().success().stdout("20240101T210000+0900\n");
+}
+
+#[test]
+fn prints_http_date_preset_in_gmt() {
+    // Arrange
+    let mut cmd = tzt_with_output_preset("http-date");
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout("Mon, 01 Jan 2024 12:00:00 GMT\n");
+}
+
+#[test]
+fn prints_sql_preset_without_zone() {
+    // Arrange
+    let mut cmd = tzt_with_output_preset("sql");
+
+    // Act & Assert
+    cmd.assert().success().stdout("2024-01-01 21:00:00\n");
+}
+
+#[test]
+fn prints_epoch_preset_in_seconds() {
+    // Arrange
+    let mut cmd = tzt_with_output_preset("epoch");
+
+    // Act & Assert
+    cmd.assert().success().stdout("1704110400\n");
+}
+
+#[test]
+fn prints_preset_with_fixed_precision() {
+    // Arrange
+    let mut cmd = tzt_with_output_preset("rfc3339");
+    cmd.args(["--precision", "3"]);
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout("2024-01-01T21:00:00.000+09:00\n");
+}
+
+#[test]
+fn fails_with_unknown_output_preset() {
+    // Arrange
+    let mut cmd = tzt_with_output_preset("rfc9999");
+
+    // Act & Assert
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid output preset"));
+}
+
+#[test]
+fn prints_translation_as_json() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args([
+        "--time",
+        "2024-11-03 01:30:00",
+        "--from",
+        "America/New_York",
+        "--to",
+        "Asia/Tokyo",
+        "--ambiguous-time-strategy",
+        "latest",
+        "--json",
+    ]);
+
+    // Act & Assert
+    cmd.assert().success().stdout(concat!(
+        r#"{"input":"2024-11-03T01:30:00","#,
+        r#""source":{"zone":"America/New_York","instant":"2024-11-03T06:30:00Z","#,
+        r#""wall_time":"2024-11-03T01:30:00","utc_offset_seconds":-18000,"#,
+        r#""abbreviation":"EST","dst":false},"#,
+        r#""ambiguity_resolution":"latest","gap_resolution":null,"#,
+        r#""targets":[{"zone":"Asia/Tokyo","wall_time":"2024-11-03T15:30:00","#,
+        r#""utc_offset_seconds":32400,"abbreviation":"JST","dst":false}]}"#,
+        "\n"
+    ));
+}
+
+#[test]
+fn prints_dst_state_of_each_target_in_json() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args([
+        "--time",
+        "2024-07-01 12:00:00",
+        "--from",
+        "UTC",
+        "--to",
+        "Europe/London,+05:30",
+        "--json",
+    ]);
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            r#"{"zone":"Europe/London","wall_time":"2024-07-01T13:00:00","utc_offset_seconds":3600,"abbreviation":"BST","dst":true}"#,
+        ))
+        .stdout(predicate::str::contains(
+            r#"{"zone":"+05:30","wall_time":"2024-07-01T17:30:00","utc_offset_seconds":19800,"abbreviation":null,"dst":false}"#,
+        ))
+        .stdout(predicate::str::contains(r#""ambiguity_resolution":null"#));
+}
+
+#[test]
+fn rejects_json_combined_with_output_preset() {
+    // Arrange
+    let mut cmd = tzt_with_output_preset("rfc3339");
+    cmd.arg("--json");
+
+    // Act & Assert
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn exits_with_code_of_failure_kind() {
+    // Arrange
+    let mut unknown_timezone = cargo_bin_cmd!("tzt");
+    unknown_timezone.args(["--time", "2024-01-01 12:00:00", "--to", "Asia/Tokio"]);
+    let mut dst_gap = cargo_bin_cmd!("tzt");
+    dst_gap.args([
+        "--time",
+        "2024-03-10 02:30:00",
+        "--from",
+        "America/New_York",
+        "--to",
+        "UTC",
+    ]);
+
+    // Act & Assert
+    unknown_timezone.assert().code(10);
+    dst_gap.assert().code(50);
+}
+
+#[test]
+fn prints_failure_as_json_with_json_flag() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args(["--time", "2024-01-", "--to", "UTC", "--json"]);
+
+    // Act & Assert
+    cmd.assert()
+        .code(3)
+        .stderr(predicate::str::starts_with(
+            r#"{"kind":"invalid_time","input":"2024-01-","exit_code":3,"message":"Validation Error: Invalid time format found."#,
+        ));
+}
+
+#[test]
+fn shifts_time_out_of_dst_gap_with_gap_strategy() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args([
+        "--time",
+        "2024-03-10 02:30:00",
+        "--from",
+        "America/New_York",
+        "--to",
+        "UTC",
+        "--gap-strategy",
+        "shift-forward",
+    ]);
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout("2024-03-10 07:00:00 UTC\n")
+        .stderr(predicate::str::contains(
+            "Adjusted to 2024-03-10 03:00:00 EDT (shift-forward)",
+        ));
+}
+
+#[test]
+fn shifts_gap_time_backward_to_the_whole_second_the_notice_names() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args([
+        "--time",
+        "2024-03-10 02:30:00",
+        "--from",
+        "America/New_York",
+        "--to",
+        "America/New_York",
+        "--gap-strategy",
+        "shift-backward",
+        "--precision",
+        "9",
+    ]);
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout("2024-03-10 01:59:59.000000000 EST\n")
+        .stderr(predicate::str::contains(
+            "Adjusted to 2024-03-10 01:59:59 EST (shift-backward)",
+        ));
+}
+
+#[test]
+fn reports_gap_resolution_in_json() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args([
+        "--time",
+        "2024-03-10 02:30:00",
+        "--from",
+        "America/New_York",
+        "--to",
+        "UTC",
+        "--gap-strategy",
+        "pre-transition-offset",
+        "--json",
+    ]);
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            r#""gap_resolution":"pre-transition-offset""#,
+        ))
+        .stderr("");
+}
+
+#[test]
+fn prints_both_occurrences_of_ambiguous_time() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args([
+        "--time",
+        "2024-11-03 01:30:00",
+        "--from",
+        "America/New_York",
+        "--to",
+        "UTC",
+        "--ambiguous-time-strategy",
+        "both",
+    ]);
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout("earliest: 2024-11-03 05:30:00 UTC\nlatest: 2024-11-03 06:30:00 UTC\n");
+}
+
+#[test]
+fn fails_on_ambiguous_time_with_error_strategy() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args([
+        "--time",
+        "2024-11-03 01:30:00",
+        "--from",
+        "America/New_York",
+        "--to",
+        "UTC",
+        "--ambiguous-time-strategy",
+        "error",
+    ]);
+
+    // Act & Assert
+    cmd.assert().code(51).stderr(predicate::str::contains(
+        "at 2024-11-03T01:30:00-04:00 EDT and at 2024-11-03T01:30:00-05:00 EST",
+    ));
+}
+
+#[test]
+fn translates_each_stdin_line() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args(["--stdin", "--from", "UTC", "--to", "Asia/Tokyo"])
+        .write_stdin("2024-01-01 12:00:00\n2024-07-01 12:00:00\n");
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout("2024-01-01 21:00:00 JST\n2024-07-01 21:00:00 JST\n");
+}
+
+#[test]
+fn reads_stdin_for_dash_time() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args([
+        "--time",
+        "-",
+        "--from",
+        "UTC",
+        "--to",
+        "Asia/Tokyo",
+        "--json",
+    ])
+    .write_stdin("2024-01-01 12:00:00\n");
+
+    // Act & Assert
+    cmd.assert().success().stdout(predicate::str::contains(
+        r#""wall_time":"2024-01-01T21:00:00""#,
+    ));
+}
+
+#[test]
+fn stops_batch_at_first_failure_by_default() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args(["--stdin", "--from", "UTC", "--to", "Asia/Tokyo"])
+        .write_stdin("2024-01-01 12:00:00\nbad\n2024-07-01 12:00:00\n");
+
+    // Act & Assert
+    cmd.assert()
+        .code(3)
+        .stdout("2024-01-01 21:00:00 JST\n")
+        .stderr(predicate::str::starts_with(
+            "line 2: Validation Error: Invalid time format found. bad",
+        ));
+}
+
+#[test]
+fn skips_failing_batch_lines() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args([
+        "--stdin",
+        "--from",
+        "UTC",
+        "--to",
+        "Asia/Tokyo",
+        "--on-error",
+        "skip",
+    ])
+    .write_stdin("bad\n2024-07-01 12:00:00\n");
+
+    // Act & Assert
+    cmd.assert().success().stdout("2024-07-01 21:00:00 JST\n");
+}
+
+#[test]
+fn passes_failing_batch_lines_through() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args([
+        "--stdin",
+        "--from",
+        "UTC",
+        "--to",
+        "Asia/Tokyo",
+        "--on-error",
+        "passthrough",
+    ])
+    .write_stdin("bad\n2024-07-01 12:00:00\n");
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout("bad\n2024-07-01 21:00:00 JST\n");
+}
+
+#[test]
+fn reports_batch_failure_line_as_json() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args(["--stdin", "--from", "UTC", "--to", "Asia/Tokyo", "--json"])
+        .write_stdin("bad\n");
+
+    // Act & Assert
+    cmd.assert()
+        .code(3)
+        .stderr(predicate::str::contains(r#""kind":"invalid_time""#))
+        .stderr(predicate::str::contains(r#""line":1"#));
+}
+
+#[test]
+fn reports_unreadable_batch_input_as_json() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args(["--stdin", "--from", "UTC", "--to", "Asia/Tokyo", "--json"])
+        .write_stdin(b"\xff\xfe\n".as_slice());
+
+    // Act & Assert
+    cmd.assert()
+        .code(1)
+        .stderr(predicate::str::contains(r#""kind":"io_error""#));
+}
+
+#[test]
+fn rejects_unknown_on_error_policy() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args(["--stdin", "--on-error", "ignore"])
+        .write_stdin("");
+
+    // Act & Assert
+    cmd.assert().code(40).stderr(predicate::str::contains(
+        "Invalid on-error policy found. ignore",
+    ));
+}
+
+#[test]
+fn translates_csv_column_in_place() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args([
+        "--csv",
+        "-",
+        "--column",
+        "created_at",
+        "--from",
+        "UTC",
+        "--to",
+        "Asia/Tokyo",
+    ])
+    .write_stdin("id,created_at,note\n1,2024-01-01 12:00:00,\"a, b\"\n");
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout("id,created_at,note\n1,2024-01-01 21:00:00 JST,\"a, b\"\n");
+}
+
+#[test]
+fn appends_tsv_column_by_position() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args([
+        "--tsv",
+        "-",
+        "--column",
+        "2",
+        "--from",
+        "UTC",
+        "--to",
+        "Asia/Tokyo",
+        "--append-column",
+        "--output",
+        "rfc3339",
+    ])
+    .write_stdin("id\tcreated_at\n1\t2024-01-01 12:00:00\n");
+
+    // Act & Assert
+    cmd.assert().success().stdout(
+        "id\tcreated_at\tcreated_at_Asia/Tokyo\n1\t2024-01-01 12:00:00\t2024-01-01T21:00:00+09:00\n",
+    );
+}
+
+#[test]
+fn skips_csv_rows_with_failing_cells() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args([
+        "--csv",
+        "-",
+        "--column",
+        "created_at",
+        "--from",
+        "UTC",
+        "--to",
+        "Asia/Tokyo",
+        "--on-error",
+        "skip",
+    ])
+    .write_stdin("id,created_at\n1,bad\n2,2024-07-01 12:00:00\n");
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout("id,created_at\n2,2024-07-01 21:00:00 JST\n")
+        .stderr(predicate::str::starts_with(
+            "line 2, column created_at: Validation Error",
+        ));
+}
+
+#[test]
+fn rejects_unknown_csv_column() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args(["--csv", "-", "--column", "updated_at", "--to", "UTC"])
+        .write_stdin("id,created_at\n");
+
+    // Act & Assert
+    cmd.assert()
+        .code(42)
+        .stderr(predicate::str::contains("Column not found. updated_at"));
+}
+
+#[test]
+fn reports_missing_csv_file_apart_from_io_errors() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args([
+        "--csv",
+        "no/such/table.csv",
+        "--column",
+        "created_at",
+        "--to",
+        "UTC",
+    ]);
+
+    // Act & Assert
+    cmd.assert()
+        .code(45)
+        .stderr(predicate::str::starts_with("IO Error: no/such/table.csv: "));
+}
+
+#[test]
+fn rejects_replacing_csv_column_with_several_targets() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args([
+        "--csv",
+        "-",
+        "--column",
+        "created_at",
+        "--to",
+        "UTC,Asia/Tokyo",
+    ])
+    .write_stdin("id,created_at\n");
+
+    // Act & Assert
+    cmd.assert()
+        .code(43)
+        .stderr(predicate::str::contains("with --append-column"));
+}
+
+#[test]
+fn rewrites_timestamps_in_log_lines() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args(["--rewrite", "--from", "UTC", "--to", "Asia/Tokyo"])
+        .write_stdin("2024-01-01T12:00:00Z INFO started\nplain line\n");
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout("2024-01-01 21:00:00 JST INFO started\nplain line\n");
+}
+
+#[test]
+fn rewrites_log_timestamps_at_their_own_offset() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args(["--rewrite", "--from", "UTC", "--to", "UTC"])
+        .write_stdin("a 2024-01-01T12:00:00+09:00 b\n");
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout("a 2024-01-01 03:00:00 UTC b\n");
+}
+
+#[test]
+fn skips_log_lines_that_fail_to_rewrite() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args([
+        "--rewrite",
+        "--from",
+        "America/New_York",
+        "--to",
+        "UTC",
+        "--on-error",
+        "skip",
+    ])
+    .write_stdin("a 2024-03-10 02:30:00\nb 2024-03-10 03:30:00\n");
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout("b 2024-03-10 07:30:00 UTC\n")
+        .stderr(predicate::str::starts_with("line 1: Translation Error"));
+}
+
+#[test]
+fn rejects_rewrite_with_several_targets() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args(["--rewrite", "--to", "UTC,Asia/Tokyo"])
+        .write_stdin("");
+
+    // Act & Assert
+    cmd.assert().code(43);
+}
+
+#[test]
+fn rejects_ndjson_with_both_occurrences() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args([
+        "--ndjson",
+        "--field",
+        ".ts",
+        "--to",
+        "UTC",
+        "--ambiguous-time-strategy",
+        "both",
+    ])
+    .write_stdin("");
+
+    // Act & Assert
+    cmd.assert()
+        .code(43)
+        .stderr(predicate::str::contains("both cannot fill a single value"));
+}
+
+#[test]
+fn translates_ndjson_fields() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args([
+        "--ndjson",
+        "--field",
+        ".ts,.request.started_at",
+        "--from",
+        "UTC",
+        "--to",
+        "Asia/Tokyo",
+        "--output",
+        "rfc3339",
+    ])
+    .write_stdin(concat!(
+        r#"{"ts":"2024-01-01T12:00:00Z","msg":"ok","request":{"started_at":"2024-01-01 11:59:59.5"}}"#,
+        "\n",
+    ));
+
+    // Act & Assert
+    cmd.assert().success().stdout(concat!(
+        r#"{"ts":"2024-01-01T21:00:00+09:00","msg":"ok","request":{"started_at":"2024-01-01T20:59:59.500+09:00"}}"#,
+        "\n",
+    ));
+}
+
+#[test]
+fn reports_invalid_ndjson_line() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args(["--ndjson", "--field", ".ts", "--to", "UTC"])
+        .write_stdin("{\"ts\":\n");
+
+    // Act & Assert
+    cmd.assert().code(44).stderr(predicate::str::starts_with(
+        "line 1: Validation Error: Invalid JSON document found.",
+    ));
+}
+
+#[test]
+fn rejects_malformed_field_path() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args(["--ndjson", "--field", "ts", "--to", "UTC"])
+        .write_stdin("");
+
+    // Act & Assert
+    cmd.assert()
+        .code(41)
+        .stderr(predicate::str::contains("Invalid field path found. ts"));
+}
+
+#[test]
+fn lists_timezones_matching_pattern() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args(["list", "tokyo"]);
+
+    // Act & Assert
+    cmd.assert().success().stdout("Asia/Tokyo  +09:00  JST\n");
+}
+
+#[test]
+fn lists_timezones_by_region_and_offset() {
+    // Arrange
+    // Asia/Kolkata has kept +05:30 all year since 1945
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args(["list", "--region", "asia", "--offset", "+05:30"]);
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Asia/Kolkata"))
+        .stdout(predicate::str::contains("Asia/Tokyo").not());
+}
+
+#[test]
+fn rejects_invalid_list_offset() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args(["list", "--offset", "+25:00"]);
+
+    // Act & Assert
+    cmd.assert()
+        .code(14)
+        .stderr(predicate::str::contains("Invalid UTC offset found. +25:00"));
+}
+
+#[test]
+fn rejects_list_with_translation_options() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args(["--to", "UTC", "list"]);
+
+    // Act & Assert
+    cmd.assert().code(2);
+}
+
+#[test]
+fn describes_zone_with_its_transitions() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args(["info", "london"]);
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Zone:                 Europe/London",
+        ))
+        .stdout(predicate::str::contains("Standard offset:      +00:00"))
+        .stdout(predicate::str::contains("Daylight offset:      +01:00"))
+        .stdout(predicate::str::contains("Next transition:      20"));
+}
+
+#[test]
+fn describes_zone_as_json() {
+    // Arrange
+    // Asia/Kolkata has kept +05:30 without DST since 1945
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args(["info", "Asia/Kolkata", "--json"]);
+
+    // Act & Assert
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            r#""standard_offset_seconds":19800"#,
+        ))
+        .stdout(predicate::str::contains(
+            r#""daylight_offset_seconds":null"#,
+        ))
+        .stdout(predicate::str::contains(r#""next_transition":null"#));
+}
+
+#[test]
+fn rejects_unknown_info_zone() {
+    // Arrange
+    let mut cmd = cargo_bin_cmd!("tzt");
+    cmd.args(["info", "Asia/Tokio", "--json"]);
+
+    // Act & Assert
+    cmd.assert()
+        .code(10)
+        .stderr(predicate::str::contains(r#""kind":"unknown_timezone""#));
+}